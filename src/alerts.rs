@@ -0,0 +1,562 @@
+use crate::cli::AlertsConfig;
+use crate::collector::{SharedSnapshot, Snapshot};
+use crate::status::validator_state_name;
+use log::{error, info, warn};
+use reqwest::Client;
+use serde::Serialize;
+use serde_json::json;
+use std::collections::HashMap;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use tokio::time::interval;
+
+const TELEGRAM_API_URL: &str = "https://api.telegram.org";
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+#[serde(rename_all = "lowercase")]
+enum AlertStatus {
+    Firing,
+    Resolved,
+}
+
+#[derive(Debug, Clone, Serialize)]
+struct Notification {
+    alert: String,
+    status: AlertStatus,
+    message: String,
+    chain_id: String,
+    validator: String,
+    timestamp: i64,
+}
+
+impl Notification {
+    fn text(&self) -> String {
+        format!(
+            "[{}] {} on {} for {}: {}",
+            match self.status {
+                AlertStatus::Firing => "FIRING",
+                AlertStatus::Resolved => "RESOLVED",
+            },
+            self.alert,
+            self.chain_id,
+            self.validator,
+            self.message
+        )
+    }
+}
+
+pub struct Alerter {
+    client: Client,
+    config: AlertsConfig,
+    validator_tm_address: String,
+    snapshot: SharedSnapshot,
+    max_snapshot_age: Duration,
+    /// Firing alerts by name with their message, used to only notify on changes
+    active: HashMap<String, String>,
+    last_validator_state: Option<i64>,
+    /// Chain id of the latest snapshot, kept for notifications sent while no snapshot is available
+    chain_id: String,
+}
+
+impl Alerter {
+    pub fn new(
+        config: AlertsConfig,
+        validator_tm_address: &str,
+        snapshot: SharedSnapshot,
+        max_snapshot_age: Duration,
+    ) -> Self {
+        let client = Client::builder()
+            .timeout(config.timeout_duration())
+            .build()
+            .expect("Failed to create HTTP client for alerts");
+
+        Self {
+            client,
+            config,
+            validator_tm_address: validator_tm_address.to_string(),
+            snapshot,
+            max_snapshot_age,
+            active: HashMap::new(),
+            last_validator_state: None,
+            chain_id: String::new(),
+        }
+    }
+
+    pub async fn start_alert_loop(&mut self) {
+        let mut interval_timer = interval(self.config.eval_interval());
+
+        info!(
+            "Starting alert loop, evaluating rules every {:?}",
+            self.config.eval_interval()
+        );
+
+        if self.config.webhooks.is_none()
+            && self.config.telegram.is_none()
+            && self.config.discord.is_none()
+        {
+            warn!("Alerts enabled without webhooks, telegram or discord notifiers");
+        }
+
+        loop {
+            interval_timer.tick().await;
+            let snapshot = self.snapshot.read().unwrap().clone();
+            let notifications = self.evaluate(snapshot.as_ref());
+            for notification in notifications {
+                self.notify(&notification).await;
+            }
+        }
+    }
+
+    /// Evaluates all rules and returns notifications for alerts which started firing or resolved.
+    fn evaluate(&mut self, snapshot: Option<&Snapshot>) -> Vec<Notification> {
+        let rules = self.config.rules.clone();
+        if let Some(snapshot) = snapshot {
+            self.chain_id = snapshot.status.node_info.network.to_string();
+        }
+        let chain_id = self.chain_id.clone();
+        let mut notifications = Vec::new();
+
+        let Some(snapshot) = snapshot.filter(|s| s.age() <= self.max_snapshot_age) else {
+            // Without a recent snapshot only node lag can be evaluated, the other alerts keep their state
+            if rules.max_node_lag.is_some() {
+                notifications.extend(self.update(
+                    "node_lag",
+                    Some("no recent data collected from the node".to_string()),
+                    &chain_id,
+                ));
+            }
+            return notifications;
+        };
+
+        if rules.state_changed.unwrap_or(false) {
+            // An unknown state, e.g. of a validator that is not found, is not reported as a change
+            if let Some(state) = snapshot.validator.namada_validator_state {
+                if let Some(last_state) = self.last_validator_state.filter(|last| *last != state) {
+                    // State changes are one-off events, they are never resolved
+                    notifications.push(self.notification(
+                        "validator_state_changed",
+                        AlertStatus::Firing,
+                        format!(
+                            "validator state changed from {} to {}",
+                            validator_state_name(last_state),
+                            validator_state_name(state)
+                        ),
+                        &chain_id,
+                    ));
+                }
+                self.last_validator_state = Some(state);
+            }
+        }
+        if let Some(max_missed_blocks) = rules.max_missed_blocks {
+            let firing = snapshot
+                .validator
+                .namada_missed_blocks
+                .filter(|missed_blocks| *missed_blocks > max_missed_blocks)
+                .map(|missed_blocks| {
+                    format!(
+                        "validator missed {} blocks, more than {}",
+                        missed_blocks, max_missed_blocks
+                    )
+                });
+            notifications.extend(self.update("missed_blocks", firing, &chain_id));
+        }
+        if let Some(rank_margin) = rules.rank_margin {
+            let rank = snapshot.validator.namada_validator_active_set_rank;
+            let max_set_size = snapshot.network.namada_network_max_set_size;
+            // Validators outside the consensus set have no rank
+            let firing = match rank {
+                None => Some("validator is not in the consensus set".to_string()),
+                Some(rank) => (rank > max_set_size - rank_margin).then(|| {
+                    format!(
+                        "validator rank {} is within {} of the max set size {}",
+                        rank, rank_margin, max_set_size
+                    )
+                }),
+            };
+            notifications.extend(self.update("active_set_rank", firing, &chain_id));
+        }
+        if let Some(max_node_lag) = rules.max_node_lag_duration() {
+            let lag = snapshot.latest_block_age();
+            let firing = (lag > max_node_lag.as_secs() as i64).then(|| {
+                format!(
+                    "latest block {} is {}s old",
+                    snapshot.node.namada_node_latest_block, lag
+                )
+            });
+            notifications.extend(self.update("node_lag", firing, &chain_id));
+        }
+        notifications
+    }
+
+    /// Updates the state of an alert and returns a notification if it started firing or resolved.
+    fn update(
+        &mut self,
+        alert: &str,
+        firing: Option<String>,
+        chain_id: &str,
+    ) -> Option<Notification> {
+        match (firing, self.active.contains_key(alert)) {
+            (Some(message), false) => {
+                self.active.insert(alert.to_string(), message.clone());
+                Some(self.notification(alert, AlertStatus::Firing, message, chain_id))
+            }
+            (None, true) => {
+                let message = self.active.remove(alert).unwrap_or_default();
+                Some(self.notification(alert, AlertStatus::Resolved, message, chain_id))
+            }
+            _ => None,
+        }
+    }
+
+    fn notification(
+        &self,
+        alert: &str,
+        status: AlertStatus,
+        message: String,
+        chain_id: &str,
+    ) -> Notification {
+        Notification {
+            alert: alert.to_string(),
+            status,
+            message,
+            chain_id: chain_id.to_string(),
+            validator: self.validator_tm_address.clone(),
+            timestamp: SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map(|d| d.as_secs() as i64)
+                .unwrap_or(-1),
+        }
+    }
+
+    async fn notify(&self, notification: &Notification) {
+        warn!("Alert {}", notification.text());
+        for webhook in self.config.webhooks.iter().flatten() {
+            let request = self.client.post(&webhook.url).json(notification);
+            self.send("webhook", request).await;
+        }
+        for telegram in self.config.telegram.iter().flatten() {
+            let url = format!(
+                "{}/bot{}/sendMessage",
+                telegram
+                    .api_url
+                    .as_deref()
+                    .unwrap_or(TELEGRAM_API_URL)
+                    .trim_end_matches('/'),
+                telegram.bot_token
+            );
+            let request = self.client.post(url).json(&json!({
+                "chat_id": telegram.chat_id,
+                "text": notification.text(),
+            }));
+            self.send("telegram", request).await;
+        }
+        for discord in self.config.discord.iter().flatten() {
+            let request = self.client.post(&discord.webhook_url).json(&json!({
+                "content": notification.text(),
+            }));
+            self.send("discord", request).await;
+        }
+    }
+
+    async fn send(&self, notifier: &str, request: reqwest::RequestBuilder) {
+        match request.send().await {
+            Ok(response) if response.status().is_success() => {
+                info!(
+                    "Sent {} alert notification: {}",
+                    notifier,
+                    response.status()
+                );
+            }
+            Ok(response) => {
+                error!(
+                    "Sending {} alert notification returned non-success status: {}",
+                    notifier,
+                    response.status()
+                );
+            }
+            Err(e) => {
+                error!("Sending {} alert notification failed: {}", notifier, e);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cli::{AlertRulesConfig, DiscordConfig, TelegramConfig, WebhookConfig};
+    use crate::collector::tests::{snapshot, CHAIN_ID};
+    use axum::extract::State;
+    use axum::http::{StatusCode, Uri};
+    use axum::routing::post;
+    use axum::{Json, Router};
+    use serde_json::Value;
+    use std::sync::{Arc, Mutex, RwLock};
+    use tokio::net::TcpListener;
+
+    const VALIDATOR: &str = "tnam1q8unq87chw62cmn6j2v7xfwr52xre06rxq6x4yuq";
+
+    fn config(rules: AlertRulesConfig) -> AlertsConfig {
+        AlertsConfig {
+            eval_rate: None,
+            timeout: Some("5s".to_string()),
+            rules,
+            webhooks: None,
+            telegram: None,
+            discord: None,
+        }
+    }
+
+    fn rules() -> AlertRulesConfig {
+        AlertRulesConfig {
+            state_changed: None,
+            max_missed_blocks: None,
+            rank_margin: None,
+            max_node_lag: None,
+        }
+    }
+
+    fn alerter(config: AlertsConfig) -> Alerter {
+        Alerter::new(
+            config,
+            VALIDATOR,
+            Arc::new(RwLock::new(None)),
+            Duration::from_secs(90),
+        )
+    }
+
+    fn alerts(notifications: &[Notification]) -> Vec<(&str, AlertStatus)> {
+        notifications
+            .iter()
+            .map(|notification| (notification.alert.as_str(), notification.status))
+            .collect()
+    }
+
+    #[test]
+    fn state_changed_fires_once_on_jailing() {
+        let mut alerter = alerter(config(AlertRulesConfig {
+            state_changed: Some(true),
+            ..rules()
+        }));
+        let mut snapshot = snapshot();
+        assert!(alerter.evaluate(Some(&snapshot)).is_empty());
+
+        snapshot.validator.namada_validator_state = Some(4);
+        snapshot.validator.namada_validator_active_set_rank = None;
+        let notifications = alerter.evaluate(Some(&snapshot));
+        assert_eq!(
+            alerts(&notifications),
+            [("validator_state_changed", AlertStatus::Firing)]
+        );
+        assert_eq!(
+            notifications[0].message,
+            "validator state changed from consensus to jailed"
+        );
+        assert_eq!(notifications[0].chain_id, CHAIN_ID);
+        assert!(alerter.evaluate(Some(&snapshot)).is_empty());
+    }
+
+    #[test]
+    fn missed_blocks_fires_once_and_resolves() {
+        let mut alerter = alerter(config(AlertRulesConfig {
+            max_missed_blocks: Some(5),
+            ..rules()
+        }));
+        let mut snapshot = snapshot();
+        snapshot.validator.namada_missed_blocks = Some(10);
+        assert_eq!(
+            alerts(&alerter.evaluate(Some(&snapshot))),
+            [("missed_blocks", AlertStatus::Firing)]
+        );
+        // Deduplicated while still firing
+        assert!(alerter.evaluate(Some(&snapshot)).is_empty());
+
+        snapshot.validator.namada_missed_blocks = Some(2);
+        let notifications = alerter.evaluate(Some(&snapshot));
+        assert_eq!(
+            alerts(&notifications),
+            [("missed_blocks", AlertStatus::Resolved)]
+        );
+        assert_eq!(
+            notifications[0].message,
+            "validator missed 10 blocks, more than 5"
+        );
+        assert!(alerter.evaluate(Some(&snapshot)).is_empty());
+    }
+
+    #[test]
+    fn active_set_rank_fires_near_max_set_size_and_outside_the_set() {
+        let mut alerter = alerter(config(AlertRulesConfig {
+            rank_margin: Some(10),
+            ..rules()
+        }));
+        let mut snapshot = snapshot();
+        assert!(alerter.evaluate(Some(&snapshot)).is_empty());
+
+        snapshot.validator.namada_validator_active_set_rank = Some(250);
+        assert_eq!(
+            alerts(&alerter.evaluate(Some(&snapshot))),
+            [("active_set_rank", AlertStatus::Firing)]
+        );
+
+        snapshot.validator.namada_validator_active_set_rank = Some(12);
+        assert_eq!(
+            alerts(&alerter.evaluate(Some(&snapshot))),
+            [("active_set_rank", AlertStatus::Resolved)]
+        );
+
+        snapshot.validator.namada_validator_active_set_rank = None;
+        let notifications = alerter.evaluate(Some(&snapshot));
+        assert_eq!(
+            alerts(&notifications),
+            [("active_set_rank", AlertStatus::Firing)]
+        );
+        assert_eq!(
+            notifications[0].message,
+            "validator is not in the consensus set"
+        );
+    }
+
+    #[test]
+    fn node_lag_fires_without_recent_snapshot() {
+        let mut alerter = alerter(config(AlertRulesConfig {
+            max_node_lag: Some("60s".to_string()),
+            state_changed: Some(true),
+            ..rules()
+        }));
+        let snapshot = snapshot();
+        assert!(alerter.evaluate(Some(&snapshot)).is_empty());
+
+        assert_eq!(
+            alerts(&alerter.evaluate(None)),
+            [("node_lag", AlertStatus::Firing)]
+        );
+        let mut stale = snapshot.clone();
+        stale.collected_at = SystemTime::now() - Duration::from_secs(300);
+        assert!(alerter.evaluate(Some(&stale)).is_empty());
+
+        assert_eq!(
+            alerts(&alerter.evaluate(Some(&snapshot))),
+            [("node_lag", AlertStatus::Resolved)]
+        );
+    }
+
+    #[test]
+    fn node_lag_fires_on_old_latest_block() {
+        let mut alerter = alerter(config(AlertRulesConfig {
+            max_node_lag: Some("60s".to_string()),
+            ..rules()
+        }));
+        let mut snapshot = snapshot();
+        snapshot.status =
+            crate::collector::tests::status_response(270000, Duration::from_secs(600));
+        let notifications = alerter.evaluate(Some(&snapshot));
+        assert_eq!(alerts(&notifications), [("node_lag", AlertStatus::Firing)]);
+        assert!(notifications[0]
+            .message
+            .starts_with("latest block 270000 is"));
+    }
+
+    type Received = Arc<Mutex<Vec<(String, Value)>>>;
+
+    async fn record(
+        State(received): State<Received>,
+        uri: Uri,
+        Json(body): Json<Value>,
+    ) -> StatusCode {
+        received
+            .lock()
+            .unwrap()
+            .push((uri.path().to_string(), body));
+        StatusCode::OK
+    }
+
+    /// Starts a local HTTP server recording the path and JSON body of every POST request.
+    async fn mock_server() -> (String, Received) {
+        let received = Received::default();
+        let app = Router::new()
+            .route("/*path", post(record))
+            .with_state(received.clone());
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        tokio::spawn(async move {
+            axum::serve(listener, app).await.unwrap();
+        });
+        (url, received)
+    }
+
+    #[tokio::test]
+    async fn notifies_webhook_telegram_and_discord() {
+        let (url, received) = mock_server().await;
+        let mut config = config(rules());
+        config.webhooks = Some(vec![WebhookConfig {
+            url: format!("{}/webhook", url),
+        }]);
+        config.telegram = Some(vec![TelegramConfig {
+            bot_token: "123456:ABC-DEF".to_string(),
+            chat_id: "-1001234567890".to_string(),
+            api_url: Some(format!("{}/", url)),
+        }]);
+        config.discord = Some(vec![DiscordConfig {
+            webhook_url: format!("{}/api/webhooks/1/token", url),
+        }]);
+        let alerter = alerter(config);
+        let notification = alerter.notification(
+            "missed_blocks",
+            AlertStatus::Firing,
+            "validator missed 10 blocks, more than 5".to_string(),
+            CHAIN_ID,
+        );
+        alerter.notify(&notification).await;
+
+        let text = format!(
+            "[FIRING] missed_blocks on {} for {}: validator missed 10 blocks, more than 5",
+            CHAIN_ID, VALIDATOR
+        );
+        let received = received.lock().unwrap();
+        assert_eq!(received.len(), 3);
+
+        let (path, body) = &received[0];
+        assert_eq!(path, "/webhook");
+        assert_eq!(body["alert"], "missed_blocks");
+        assert_eq!(body["status"], "firing");
+        assert_eq!(body["message"], "validator missed 10 blocks, more than 5");
+        assert_eq!(body["chain_id"], CHAIN_ID);
+        assert_eq!(body["validator"], VALIDATOR);
+
+        let (path, body) = &received[1];
+        assert_eq!(path, "/bot123456:ABC-DEF/sendMessage");
+        assert_eq!(body["chat_id"], "-1001234567890");
+        assert_eq!(body["text"], text.as_str());
+
+        let (path, body) = &received[2];
+        assert_eq!(path, "/api/webhooks/1/token");
+        assert_eq!(body["content"], text.as_str());
+    }
+
+    #[tokio::test]
+    async fn notifier_failures_do_not_stop_other_notifiers() {
+        let (url, received) = mock_server().await;
+        let mut config = config(rules());
+        config.webhooks = Some(vec![
+            WebhookConfig {
+                // Nothing listens on port 9 of localhost
+                url: "http://127.0.0.1:9/webhook".to_string(),
+            },
+            WebhookConfig {
+                url: format!("{}/webhook", url),
+            },
+        ]);
+        let alerter = alerter(config);
+        let notification = alerter.notification(
+            "node_lag",
+            AlertStatus::Resolved,
+            "latest block 270000 is 600s old".to_string(),
+            CHAIN_ID,
+        );
+        alerter.notify(&notification).await;
+
+        let received = received.lock().unwrap();
+        assert_eq!(received.len(), 1);
+        assert_eq!(received[0].1["status"], "resolved");
+    }
+}
@@ -0,0 +1,54 @@
+use log::warn;
+use namada_sdk::token::{Amount, DenominatedAmount, Denomination, NATIVE_MAX_DECIMAL_PLACES};
+
+/// Converts a token amount into a float expressed in whole tokens using the given denomination.
+/// Amounts which can not be represented as a float are reported as -1.
+pub fn to_denominated_f64(amount: Amount, denom: u8) -> f64 {
+    let denominated = DenominatedAmount::new(amount, Denomination(denom));
+    parse_f64(denominated.to_string())
+}
+
+/// Converts a NAM amount into a float expressed in NAM.
+pub fn to_nam_f64(amount: Amount) -> f64 {
+    to_denominated_f64(amount, NATIVE_MAX_DECIMAL_PLACES)
+}
+
+/// Converts a token amount into a float expressed in raw (micro) units.
+pub fn to_raw_f64(amount: Amount) -> f64 {
+    parse_f64(amount.raw_amount().to_string())
+}
+
+fn parse_f64(value: String) -> f64 {
+    match value.parse::<f64>() {
+        Ok(parsed) if parsed.is_finite() => parsed,
+        _ => {
+            warn!("Could not represent token amount {} as a float", value);
+            -1.0
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn converts_micro_units_to_nam() {
+        let amount = Amount::from_uint(1_520_000_500_000u64, 0).unwrap();
+        assert_eq!(to_nam_f64(amount), 1_520_000.5);
+        assert_eq!(to_raw_f64(amount), 1_520_000_500_000.0);
+    }
+
+    #[test]
+    fn converts_with_token_denomination() {
+        let amount = Amount::from_uint(1_234_500_000_000_000_000u64, 0).unwrap();
+        assert_eq!(to_denominated_f64(amount, 18), 1.2345);
+        assert_eq!(to_denominated_f64(amount, 0), 1_234_500_000_000_000_000.0);
+    }
+
+    #[test]
+    fn converts_zero() {
+        assert_eq!(to_nam_f64(Amount::zero()), 0.0);
+        assert_eq!(to_raw_f64(Amount::zero()), 0.0);
+    }
+}
@@ -0,0 +1,122 @@
+use crate::cli::AuthConfig;
+use axum::{
+    extract::{Request, State},
+    http::{
+        header::{AUTHORIZATION, WWW_AUTHENTICATE},
+        StatusCode,
+    },
+    middleware::Next,
+    response::{IntoResponse, Response},
+};
+use base64::{engine::general_purpose::STANDARD, Engine};
+use log::warn;
+
+/// Accepted `Authorization` header values, precomputed from the configured credentials.
+#[derive(Clone)]
+pub struct Authenticator {
+    authorizations: Vec<String>,
+}
+
+impl Authenticator {
+    pub fn new(config: &AuthConfig) -> Self {
+        let mut authorizations = Vec::new();
+        match (&config.username, &config.password) {
+            (Some(username), Some(password)) => {
+                let credentials = STANDARD.encode(format!("{}:{}", username, password));
+                authorizations.push(format!("Basic {}", credentials));
+            }
+            (None, None) => {}
+            _ => warn!("Basic auth needs both username and password, basic auth is disabled"),
+        }
+        if let Some(bearer_token) = &config.bearer_token {
+            authorizations.push(format!("Bearer {}", bearer_token));
+        }
+        if authorizations.is_empty() {
+            warn!("Auth is configured without credentials, all requests will be rejected");
+        }
+        Self { authorizations }
+    }
+
+    fn is_authorized(&self, authorization: &[u8]) -> bool {
+        self.authorizations
+            .iter()
+            .any(|expected| constant_time_eq(expected.as_bytes(), authorization))
+    }
+}
+
+pub async fn auth_middleware(
+    State(authenticator): State<Authenticator>,
+    request: Request,
+    next: Next,
+) -> Response {
+    let authorized = request
+        .headers()
+        .get(AUTHORIZATION)
+        .is_some_and(|authorization| authenticator.is_authorized(authorization.as_bytes()));
+    if authorized {
+        next.run(request).await
+    } else {
+        (
+            StatusCode::UNAUTHORIZED,
+            [(WWW_AUTHENTICATE, "Basic realm=\"namada-exporter\"")],
+            "Unauthorized",
+        )
+            .into_response()
+    }
+}
+
+/// Compares without returning early, so the time taken does not leak how much of a secret matched.
+fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    a.len() == b.len() && a.iter().zip(b).fold(0, |acc, (x, y)| acc | (x ^ y)) == 0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn authenticator(
+        username: Option<&str>,
+        password: Option<&str>,
+        bearer_token: Option<&str>,
+    ) -> Authenticator {
+        Authenticator::new(&AuthConfig {
+            username: username.map(str::to_string),
+            password: password.map(str::to_string),
+            bearer_token: bearer_token.map(str::to_string),
+        })
+    }
+
+    #[test]
+    fn accepts_basic_and_bearer_credentials() {
+        let authenticator = authenticator(Some("prometheus"), Some("secret"), Some("token"));
+        // base64("prometheus:secret")
+        assert!(authenticator.is_authorized(b"Basic cHJvbWV0aGV1czpzZWNyZXQ="));
+        assert!(authenticator.is_authorized(b"Bearer token"));
+    }
+
+    #[test]
+    fn rejects_wrong_credentials() {
+        let authenticator = authenticator(Some("prometheus"), Some("secret"), Some("token"));
+        // base64("prometheus:wrong")
+        assert!(!authenticator.is_authorized(b"Basic cHJvbWV0aGV1czp3cm9uZw=="));
+        assert!(!authenticator.is_authorized(b"Bearer toke"));
+        assert!(!authenticator.is_authorized(b"Bearer tokens"));
+        assert!(!authenticator.is_authorized(b"token"));
+        assert!(!authenticator.is_authorized(b""));
+    }
+
+    #[test]
+    fn basic_auth_needs_username_and_password() {
+        let authenticator = authenticator(Some("prometheus"), None, None);
+        // base64("prometheus:")
+        assert!(!authenticator.is_authorized(b"Basic cHJvbWV0aGV1czo="));
+        assert!(authenticator.authorizations.is_empty());
+    }
+
+    #[test]
+    fn constant_time_eq_compares_content_and_length() {
+        assert!(constant_time_eq(b"Bearer token", b"Bearer token"));
+        assert!(!constant_time_eq(b"Bearer token", b"Bearer tokem"));
+        assert!(!constant_time_eq(b"Bearer token", b"Bearer token "));
+    }
+}
@@ -0,0 +1,548 @@
+use crate::amount::to_nam_f64;
+use crate::cli::BlockFollowerConfig;
+use crate::constants::RECENT_VALIDATOR_EVENTS;
+use crate::namada_query::{BlockTxs, Query};
+use borsh::BorshDeserialize;
+use log::{debug, error, info, warn};
+use namada_sdk::address::Address;
+use namada_sdk::tx::data::pos::{
+    Bond, ClaimRewards, CommissionChange, MetaDataChange, Redelegation, Unbond, Withdraw,
+};
+use serde::Serialize;
+use std::collections::{HashMap, VecDeque};
+use std::str::FromStr;
+use std::sync::{Arc, RwLock};
+use tokio::time::interval;
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ValidatorEvent {
+    pub height: u64,
+    pub time: i64,
+    pub tx_hash: String,
+    pub event_type: String,
+    pub succeeded: bool,
+    pub source: Option<String>,
+    /// Amount in NAM for bonds, unbonds and redelegations
+    pub amount: Option<f64>,
+    pub commission_rate: Option<String>,
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct TxStats {
+    pub last_height: u64,
+    pub last_block_txs: u64,
+    pub last_block_gas_used: u64,
+    pub block_gas_limit: u64,
+    pub blocks: u64,
+    pub txs_succeeded: u64,
+    pub txs_failed: u64,
+    pub gas_used: u64,
+    /// Number of inner transactions per transaction type, e.g. `transfer`, `bond`, `vote_proposal`
+    pub txs_by_type: HashMap<String, u64>,
+    /// Number of successful transactions touching the validator per event type
+    pub validator_events: HashMap<String, u64>,
+    /// Total amount in NAM of successful transactions touching the validator per event type
+    pub validator_event_amounts: HashMap<String, f64>,
+    pub recent_validator_events: VecDeque<ValidatorEvent>,
+}
+
+impl TxStats {
+    fn record_block(&mut self, height: u64, block_txs: &BlockTxs, validator: Option<&Address>) {
+        let txs = &block_txs.txs;
+        self.last_height = height;
+        self.last_block_txs = txs.len() as u64;
+        self.last_block_gas_used = txs.iter().map(|tx| tx.gas_used).sum();
+        self.blocks += 1;
+        self.gas_used += self.last_block_gas_used;
+        for tx in txs {
+            if tx.succeeded {
+                self.txs_succeeded += 1;
+            } else {
+                self.txs_failed += 1;
+            }
+            if tx.inner_txs.is_empty() {
+                *self.txs_by_type.entry("other".to_string()).or_default() += 1;
+            }
+            for inner_tx in &tx.inner_txs {
+                let tx_type = tx_type(inner_tx.code_tag.as_deref().unwrap_or("unknown"));
+                *self.txs_by_type.entry(tx_type.clone()).or_default() += 1;
+                let (Some(validator), Some(data)) = (validator, &inner_tx.data) else {
+                    continue;
+                };
+                let Some(mut event) = decode_validator_event(validator, &tx_type, data) else {
+                    continue;
+                };
+                event.height = height;
+                event.time = block_txs.time;
+                event.tx_hash = tx.hash.clone();
+                event.succeeded = tx.succeeded;
+                self.record_validator_event(event);
+            }
+        }
+    }
+
+    fn record_validator_event(&mut self, event: ValidatorEvent) {
+        info!(
+            "Validator {} event in block {}: {}",
+            event.event_type, event.height, event.tx_hash
+        );
+        if event.succeeded {
+            *self
+                .validator_events
+                .entry(event.event_type.clone())
+                .or_default() += 1;
+            if let Some(amount) = event.amount {
+                *self
+                    .validator_event_amounts
+                    .entry(event.event_type.clone())
+                    .or_default() += amount;
+            }
+        }
+        if self.recent_validator_events.len() == RECENT_VALIDATOR_EVENTS {
+            self.recent_validator_events.pop_back();
+        }
+        self.recent_validator_events.push_front(event);
+    }
+}
+
+fn validator_event(
+    event_type: &str,
+    source: Option<&Address>,
+    amount: Option<f64>,
+) -> ValidatorEvent {
+    ValidatorEvent {
+        height: 0,
+        time: 0,
+        tx_hash: String::new(),
+        event_type: event_type.to_string(),
+        succeeded: false,
+        source: source.map(|source| source.to_string()),
+        amount,
+        commission_rate: None,
+    }
+}
+
+/// Decodes the data of an inner transaction and returns an event if it touches the validator.
+fn decode_validator_event(
+    validator: &Address,
+    tx_type: &str,
+    data: &[u8],
+) -> Option<ValidatorEvent> {
+    match tx_type {
+        "bond" => {
+            let bond = Bond::try_from_slice(data).ok()?;
+            (bond.validator == *validator).then(|| {
+                validator_event("bond", bond.source.as_ref(), Some(to_nam_f64(bond.amount)))
+            })
+        }
+        "unbond" => {
+            let unbond = Unbond::try_from_slice(data).ok()?;
+            (unbond.validator == *validator).then(|| {
+                validator_event(
+                    "unbond",
+                    unbond.source.as_ref(),
+                    Some(to_nam_f64(unbond.amount)),
+                )
+            })
+        }
+        "redelegate" => {
+            let redelegation = Redelegation::try_from_slice(data).ok()?;
+            let amount = Some(to_nam_f64(redelegation.amount));
+            if redelegation.dest_validator == *validator {
+                Some(validator_event(
+                    "redelegation_in",
+                    Some(&redelegation.owner),
+                    amount,
+                ))
+            } else if redelegation.src_validator == *validator {
+                Some(validator_event(
+                    "redelegation_out",
+                    Some(&redelegation.owner),
+                    amount,
+                ))
+            } else {
+                None
+            }
+        }
+        "withdraw" => {
+            let withdraw = Withdraw::try_from_slice(data).ok()?;
+            (withdraw.validator == *validator)
+                .then(|| validator_event("withdraw", withdraw.source.as_ref(), None))
+        }
+        "claim_rewards" => {
+            let claim = ClaimRewards::try_from_slice(data).ok()?;
+            (claim.validator == *validator)
+                .then(|| validator_event("claim_rewards", claim.source.as_ref(), None))
+        }
+        "change_validator_commission" => {
+            let change = CommissionChange::try_from_slice(data).ok()?;
+            (change.validator == *validator).then(|| ValidatorEvent {
+                commission_rate: Some(change.new_rate.to_string()),
+                ..validator_event("commission_change", None, None)
+            })
+        }
+        "change_validator_metadata" => {
+            let change = MetaDataChange::try_from_slice(data).ok()?;
+            (change.validator == *validator).then(|| ValidatorEvent {
+                commission_rate: change.commission_rate.map(|rate| rate.to_string()),
+                ..validator_event("metadata_change", None, None)
+            })
+        }
+        "unjail_validator" | "deactivate_validator" | "reactivate_validator" => {
+            let address = Address::try_from_slice(data).ok()?;
+            (address == *validator).then(|| validator_event(tx_type, None, None))
+        }
+        _ => None,
+    }
+}
+
+/// Turns a wasm code tag such as `tx_transfer.wasm` into a transaction type such as `transfer`.
+fn tx_type(tag: &str) -> String {
+    tag.trim_start_matches("tx_")
+        .trim_end_matches(".wasm")
+        .to_string()
+}
+
+pub struct BlockFollower {
+    q: Query,
+    config: BlockFollowerConfig,
+    validator: Option<Address>,
+    stats: Arc<RwLock<TxStats>>,
+}
+
+impl BlockFollower {
+    pub fn new(
+        config: BlockFollowerConfig,
+        q: Query,
+        validator_tm_address: &str,
+        stats: Arc<RwLock<TxStats>>,
+    ) -> Self {
+        let validator = match Address::from_str(validator_tm_address) {
+            Ok(validator) => Some(validator),
+            Err(e) => {
+                warn!(
+                    "Invalid validator address {}, validator transactions will not be tracked: {}",
+                    validator_tm_address, e
+                );
+                None
+            }
+        };
+        Self {
+            q,
+            config,
+            validator,
+            stats,
+        }
+    }
+
+    pub async fn start_follow_loop(&self) {
+        let mut interval_timer = interval(self.config.poll_interval());
+
+        info!(
+            "Starting block follower loop, polling every {}",
+            self.config.poll_rate
+        );
+
+        loop {
+            interval_timer.tick().await;
+            self.follow();
+        }
+    }
+
+    fn follow(&self) {
+        let latest_height = match self.q.status() {
+            Ok(status) => status.sync_info.latest_block_height.value(),
+            Err(e) => {
+                error!("Block follower could not query status: {}", e);
+                return;
+            }
+        };
+        match self.q.query_max_block_gas() {
+            Ok(max_block_gas) => self.stats.write().unwrap().block_gas_limit = max_block_gas,
+            Err(e) => error!("Block follower could not query max block gas: {}", e),
+        }
+        let last_height = self.stats.read().unwrap().last_height;
+        // Start from the latest block on the first run instead of replaying the whole chain
+        let from_height = if last_height == 0 {
+            latest_height
+        } else {
+            last_height + 1
+        };
+        for height in from_height..=latest_height {
+            match self.q.query_block_txs(height) {
+                Ok(block_txs) => {
+                    debug!("Block follower processed block {}: {:?}", height, block_txs);
+                    self.stats.write().unwrap().record_block(
+                        height,
+                        &block_txs,
+                        self.validator.as_ref(),
+                    );
+                }
+                Err(e) => {
+                    error!("Block follower could not query block {}: {}", height, e);
+                    return;
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::namada_query::{BlockTx, InnerTx};
+    use namada_sdk::dec::Dec;
+    use namada_sdk::token::Amount;
+
+    fn address(byte: u8) -> Address {
+        Address::Established([byte; 20].into())
+    }
+
+    fn amount(micro_nam: u64) -> Amount {
+        Amount::from_uint(micro_nam, 0).unwrap()
+    }
+
+    fn inner_tx(code_tag: &str, data: Option<Vec<u8>>) -> InnerTx {
+        InnerTx {
+            code_tag: Some(code_tag.to_string()),
+            data,
+        }
+    }
+
+    fn block_tx(succeeded: bool, gas_used: u64, inner_txs: Vec<InnerTx>) -> BlockTx {
+        BlockTx {
+            hash: format!("TX{}", gas_used),
+            succeeded,
+            gas_used,
+            inner_txs,
+        }
+    }
+
+    fn bond_data(validator: &Address, micro_nam: u64) -> Vec<u8> {
+        borsh::to_vec(&Bond {
+            validator: validator.clone(),
+            amount: amount(micro_nam),
+            source: Some(address(9)),
+        })
+        .unwrap()
+    }
+
+    #[test]
+    fn tx_type_strips_wasm_tag() {
+        assert_eq!(tx_type("tx_transfer.wasm"), "transfer");
+        assert_eq!(tx_type("tx_vote_proposal.wasm"), "vote_proposal");
+        assert_eq!(tx_type("tx_ibc.wasm"), "ibc");
+        assert_eq!(tx_type("unknown"), "unknown");
+    }
+
+    #[test]
+    fn record_block_counts_txs_gas_and_types() {
+        let mut stats = TxStats::default();
+        let block_txs = BlockTxs {
+            time: 1_700_000_000,
+            txs: vec![
+                block_tx(true, 50_000, vec![inner_tx("tx_transfer.wasm", None)]),
+                block_tx(
+                    false,
+                    20_000,
+                    vec![
+                        inner_tx("tx_bond.wasm", None),
+                        inner_tx("tx_transfer.wasm", None),
+                    ],
+                ),
+                block_tx(true, 5_000, vec![]),
+            ],
+        };
+        stats.record_block(100, &block_txs, None);
+        assert_eq!(stats.last_height, 100);
+        assert_eq!(stats.last_block_txs, 3);
+        assert_eq!(stats.last_block_gas_used, 75_000);
+        assert_eq!(stats.txs_succeeded, 2);
+        assert_eq!(stats.txs_failed, 1);
+        assert_eq!(stats.txs_by_type["transfer"], 2);
+        assert_eq!(stats.txs_by_type["bond"], 1);
+        assert_eq!(stats.txs_by_type["other"], 1);
+
+        let block_txs = BlockTxs {
+            time: 1_700_000_006,
+            txs: vec![block_tx(
+                true,
+                10_000,
+                vec![inner_tx("tx_transfer.wasm", None)],
+            )],
+        };
+        stats.record_block(101, &block_txs, None);
+        assert_eq!(stats.last_height, 101);
+        assert_eq!(stats.last_block_txs, 1);
+        assert_eq!(stats.last_block_gas_used, 10_000);
+        assert_eq!(stats.blocks, 2);
+        assert_eq!(stats.gas_used, 85_000);
+        assert_eq!(stats.txs_by_type["transfer"], 3);
+    }
+
+    #[test]
+    fn record_empty_block() {
+        let mut stats = TxStats::default();
+        let block_txs = BlockTxs {
+            time: 1_700_000_000,
+            txs: vec![],
+        };
+        stats.record_block(100, &block_txs, None);
+        assert_eq!(stats.blocks, 1);
+        assert_eq!(stats.last_block_txs, 0);
+        assert_eq!(stats.last_block_gas_used, 0);
+        assert!(stats.txs_by_type.is_empty());
+    }
+
+    #[test]
+    fn record_block_tracks_validator_events() {
+        let validator = address(1);
+        let mut stats = TxStats::default();
+        let block_txs = BlockTxs {
+            time: 1_700_000_000,
+            txs: vec![
+                block_tx(
+                    true,
+                    50_000,
+                    vec![inner_tx(
+                        "tx_bond.wasm",
+                        Some(bond_data(&validator, 1_500_000)),
+                    )],
+                ),
+                block_tx(
+                    false,
+                    40_000,
+                    vec![inner_tx(
+                        "tx_bond.wasm",
+                        Some(bond_data(&validator, 7_000_000)),
+                    )],
+                ),
+                block_tx(
+                    true,
+                    30_000,
+                    vec![inner_tx(
+                        "tx_bond.wasm",
+                        Some(bond_data(&address(2), 1_000_000)),
+                    )],
+                ),
+            ],
+        };
+        stats.record_block(100, &block_txs, Some(&validator));
+        // Failed transactions are listed in the recent events but not counted
+        assert_eq!(stats.validator_events["bond"], 1);
+        assert_eq!(stats.validator_event_amounts["bond"], 1.5);
+        assert_eq!(stats.recent_validator_events.len(), 2);
+        let latest = &stats.recent_validator_events[0];
+        assert_eq!(latest.height, 100);
+        assert_eq!(latest.time, 1_700_000_000);
+        assert_eq!(latest.tx_hash, "TX40000");
+        assert!(!latest.succeeded);
+        assert_eq!(latest.amount, Some(7.0));
+        assert_eq!(latest.source, Some(address(9).to_string()));
+    }
+
+    #[test]
+    fn recent_validator_events_are_bounded() {
+        let mut stats = TxStats::default();
+        for height in 0..RECENT_VALIDATOR_EVENTS as u64 + 5 {
+            stats.record_validator_event(ValidatorEvent {
+                height,
+                ..validator_event("bond", None, Some(1.0))
+            });
+        }
+        assert_eq!(stats.recent_validator_events.len(), RECENT_VALIDATOR_EVENTS);
+        assert_eq!(
+            stats.recent_validator_events[0].height,
+            RECENT_VALIDATOR_EVENTS as u64 + 4
+        );
+    }
+
+    #[test]
+    fn decodes_bonds_and_unbonds_of_the_validator() {
+        let validator = address(1);
+        let event =
+            decode_validator_event(&validator, "bond", &bond_data(&validator, 2_000_000)).unwrap();
+        assert_eq!(event.event_type, "bond");
+        assert_eq!(event.amount, Some(2.0));
+        assert_eq!(event.source, Some(address(9).to_string()));
+
+        let event =
+            decode_validator_event(&validator, "unbond", &bond_data(&validator, 500_000)).unwrap();
+        assert_eq!(event.event_type, "unbond");
+        assert_eq!(event.amount, Some(0.5));
+
+        assert!(
+            decode_validator_event(&validator, "bond", &bond_data(&address(2), 2_000_000))
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn decodes_redelegations_in_both_directions() {
+        let validator = address(1);
+        let redelegation = |src_validator: Address, dest_validator: Address| {
+            borsh::to_vec(&Redelegation {
+                src_validator,
+                dest_validator,
+                owner: address(9),
+                amount: amount(3_000_000),
+            })
+            .unwrap()
+        };
+        let event = decode_validator_event(
+            &validator,
+            "redelegate",
+            &redelegation(address(2), validator.clone()),
+        )
+        .unwrap();
+        assert_eq!(event.event_type, "redelegation_in");
+        assert_eq!(event.amount, Some(3.0));
+        assert_eq!(event.source, Some(address(9).to_string()));
+
+        let event = decode_validator_event(
+            &validator,
+            "redelegate",
+            &redelegation(validator.clone(), address(2)),
+        )
+        .unwrap();
+        assert_eq!(event.event_type, "redelegation_out");
+
+        assert!(decode_validator_event(
+            &validator,
+            "redelegate",
+            &redelegation(address(2), address(3)),
+        )
+        .is_none());
+    }
+
+    #[test]
+    fn decodes_commission_changes_and_state_changes() {
+        let validator = address(1);
+        let new_rate = Dec::from_str("0.05").unwrap();
+        let data = borsh::to_vec(&CommissionChange {
+            validator: validator.clone(),
+            new_rate,
+        })
+        .unwrap();
+        let event =
+            decode_validator_event(&validator, "change_validator_commission", &data).unwrap();
+        assert_eq!(event.event_type, "commission_change");
+        assert_eq!(event.commission_rate, Some(new_rate.to_string()));
+        assert_eq!(event.amount, None);
+
+        let data = borsh::to_vec(&validator).unwrap();
+        let event = decode_validator_event(&validator, "unjail_validator", &data).unwrap();
+        assert_eq!(event.event_type, "unjail_validator");
+        let data = borsh::to_vec(&address(2)).unwrap();
+        assert!(decode_validator_event(&validator, "unjail_validator", &data).is_none());
+    }
+
+    #[test]
+    fn ignores_undecodable_and_unrelated_txs() {
+        let validator = address(1);
+        assert!(decode_validator_event(&validator, "bond", &[1, 2, 3]).is_none());
+        assert!(
+            decode_validator_event(&validator, "transfer", &bond_data(&validator, 1)).is_none()
+        );
+    }
+}
@@ -1,11 +1,18 @@
 use crate::constants;
-use clap::{Parser, Subcommand};
+use crate::validate::validate;
+use clap::{Args, Parser, Subcommand};
+use figment::providers::{Env, Format, Serialized, Toml};
+use figment::value::Value;
+use figment::Figment;
 use humantime::parse_duration;
-use serde::Deserialize;
-use std::fs;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use std::path::PathBuf;
+use std::process;
 use std::time::Duration;
 
+const ENV_PREFIX: &str = "NAMADA_EXPORTER_";
+
 /// Example CLI application
 #[derive(Parser, Debug)]
 #[command(name = env!("CARGO_CRATE_NAME"), version = env!("CARGO_PKG_VERSION"), about = env!("CARGO_PKG_DESCRIPTION"), author = env!("CARGO_PKG_AUTHORS"))]
@@ -16,27 +23,280 @@ pub struct Cli {
 
 #[derive(Subcommand, Debug)]
 pub enum Commands {
-    /// Run the exporter with a configuration file
+    /// Run the exporter with a configuration file, environment variables and flags
     Start {
-        /// Path to the config.toml file
-        #[arg(short = 'c', long, value_name = "CONFIG_FILE")]
-        config: PathBuf,
+        #[command(flatten)]
+        config: ConfigArgs,
+    },
+    /// Check the configuration, the validator address and the RPC, printing all problems found
+    Validate {
+        #[command(flatten)]
+        config: ConfigArgs,
     },
 }
+
+/// Configuration sources. Flags take precedence over `NAMADA_EXPORTER_*` environment variables,
+/// which take precedence over the config file.
+#[derive(Args, Debug, Serialize)]
+pub struct ConfigArgs {
+    /// Path to the config.toml file
+    #[arg(short = 'c', long, value_name = "CONFIG_FILE")]
+    #[serde(skip)]
+    pub config: Option<PathBuf>,
+    #[arg(long)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub host: Option<String>,
+    #[arg(long)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub validator_tm_address: Option<String>,
+    #[arg(long)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub http_rpc: Option<String>,
+    #[arg(long)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub chain_id: Option<String>,
+    #[arg(long)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metrics_content_type: Option<String>,
+    #[arg(long)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub export_raw_amounts: Option<bool>,
+    #[arg(long)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expected_version: Option<String>,
+    #[arg(long)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub collect_rate: Option<String>,
+    #[arg(long)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub freshness_window: Option<String>,
+    /// Set any config field by its dotted path, e.g. `--set healthcheck.ping_url=https://hc-ping.com/id`
+    #[arg(long = "set", value_name = "KEY=VALUE", value_parser = parse_key_value)]
+    #[serde(skip)]
+    pub overrides: Vec<(String, String)>,
+}
+
+fn parse_key_value(arg: &str) -> Result<(String, String), String> {
+    arg.split_once('=')
+        .map(|(key, value)| (key.trim().to_string(), value.to_string()))
+        .ok_or_else(|| format!("expected KEY=VALUE, got {}", arg))
+}
 #[derive(Debug, Deserialize, Clone)]
 pub struct ExporterConfig {
     pub host: String,
     pub validator_tm_address: String,
     pub http_rpc: String,
+    /// Chain id the RPC node is expected to be on, checked by `validate`
+    pub chain_id: Option<String>,
     pub metrics_content_type: Option<String>,
+    pub tls: Option<TlsConfig>,
+    pub auth: Option<AuthConfig>,
+    pub export_raw_amounts: Option<bool>,
+    pub expected_version: Option<String>,
+    /// How often the background collector refreshes the latest snapshot
+    pub collect_rate: Option<String>,
+    /// Maximum age of the latest snapshot before it counts as stale, defaults to three times `collect_rate`
+    pub freshness_window: Option<String>,
     pub healthcheck: Option<HealthCheckConfig>,
+    pub block_follower: Option<BlockFollowerConfig>,
+    pub pgf: Option<PgfConfig>,
+    pub balances: Option<Vec<BalanceConfig>>,
+    pub masp: Option<MaspConfig>,
+    pub ibc: Option<IbcConfig>,
+    pub tokens: Option<Vec<TokenConfig>>,
+    pub upgrade: Option<UpgradeConfig>,
+    pub alerts: Option<AlertsConfig>,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct TlsConfig {
+    pub cert_path: PathBuf,
+    pub key_path: PathBuf,
+    /// How often to check the certificate and key for changes
+    pub reload_rate: Option<String>,
+}
+
+#[derive(Deserialize, Clone)]
+pub struct AuthConfig {
+    /// HTTP basic auth credentials
+    pub username: Option<String>,
+    pub password: Option<String>,
+    pub bearer_token: Option<String>,
+}
+
+// Credentials are never logged
+impl std::fmt::Debug for AuthConfig {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("AuthConfig")
+            .field("username", &self.username)
+            .field("password", &self.password.as_ref().map(|_| "***"))
+            .field("bearer_token", &self.bearer_token.as_ref().map(|_| "***"))
+            .finish()
+    }
 }
 
 #[derive(Debug, Deserialize, Clone)]
 pub struct HealthCheckConfig {
-    pub ping_url: String,
+    /// Shorthand for a single GET target
+    pub ping_url: Option<String>,
     pub ping_rate: String,
     pub timeout: String,
+    /// URL pinged when a condition fails, defaults to `<ping_url>/fail`
+    pub fail_url: Option<String>,
+    /// Number of retries of a failed ping
+    pub retries: Option<u32>,
+    /// Delay before the first retry, doubled for every following retry
+    pub retry_backoff: Option<String>,
+    pub targets: Option<Vec<HealthCheckTarget>>,
+    pub conditions: Option<HealthCheckConditions>,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct HealthCheckTarget {
+    /// URL pinged when all conditions pass, may contain status placeholders
+    pub url: String,
+    /// URL pinged when a condition fails, defaults to `<url>/fail`
+    pub fail_url: Option<String>,
+    /// GET, POST or HEAD, defaults to GET
+    pub method: Option<String>,
+    pub headers: Option<HashMap<String, String>>,
+    /// Request body, may contain status placeholders
+    pub body: Option<String>,
+    /// Status codes counted as a successful ping, defaults to any 2xx status
+    pub expected_status: Option<Vec<u16>>,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct HealthCheckConditions {
+    pub not_catching_up: Option<bool>,
+    /// Maximum age of the latest block, i.e. the block height must have advanced within this duration
+    pub max_block_age: Option<String>,
+    pub consensus_state: Option<bool>,
+    pub max_missed_blocks: Option<i64>,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct AlertsConfig {
+    pub eval_rate: Option<String>,
+    pub timeout: Option<String>,
+    pub rules: AlertRulesConfig,
+    pub webhooks: Option<Vec<WebhookConfig>>,
+    pub telegram: Option<Vec<TelegramConfig>>,
+    pub discord: Option<Vec<DiscordConfig>>,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct AlertRulesConfig {
+    /// Notify when the validator state changes, e.g. from consensus to jailed
+    pub state_changed: Option<bool>,
+    pub max_missed_blocks: Option<i64>,
+    /// Fire when the validator rank is above `max_set_size - rank_margin`
+    pub rank_margin: Option<i64>,
+    /// Maximum age of the latest block known to the node
+    pub max_node_lag: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct WebhookConfig {
+    pub url: String,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct TelegramConfig {
+    pub bot_token: String,
+    pub chat_id: String,
+    /// Bot API base URL, defaults to https://api.telegram.org
+    pub api_url: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct DiscordConfig {
+    pub webhook_url: String,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct BlockFollowerConfig {
+    pub poll_rate: String,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct PgfConfig {
+    pub stewards: Vec<String>,
+    /// Number of latest governance proposals scanned for retroactive PGF payments
+    pub retro_proposals: Option<u64>,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct BalanceConfig {
+    pub address: String,
+    /// Token address, defaults to the native token
+    pub token: Option<String>,
+    pub alias: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct TokenConfig {
+    pub address: String,
+    pub alias: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct UpgradeConfig {
+    pub height: u64,
+    pub name: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct MaspConfig {
+    /// Additional token addresses to report besides the MASP reward tokens
+    pub tokens: Option<Vec<String>>,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct IbcConfig {
+    pub clients: Option<Vec<String>>,
+    pub channels: Option<Vec<IbcChannelConfig>>,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct IbcChannelConfig {
+    /// Port of the channel, defaults to `transfer`
+    pub port_id: Option<String>,
+    pub channel_id: String,
+}
+
+impl IbcChannelConfig {
+    pub fn port_id(&self) -> &str {
+        self.port_id
+            .as_deref()
+            .unwrap_or(constants::DEFAULT_IBC_PORT_ID)
+    }
+}
+
+impl ExporterConfig {
+    pub fn collect_interval(&self) -> Duration {
+        self.collect_rate
+            .as_ref()
+            .and_then(|rate| parse_duration(rate).ok())
+            .unwrap_or_else(|| Duration::from_secs(constants::DEFAULT_COLLECT_RATE_IN_SECONDS))
+    }
+
+    pub fn freshness_window(&self) -> Duration {
+        self.freshness_window
+            .as_ref()
+            .and_then(|window| parse_duration(window).ok())
+            // Allow a couple of slow or skipped collections before the snapshot counts as stale
+            .unwrap_or_else(|| self.collect_interval() * 3)
+    }
+}
+
+impl TlsConfig {
+    pub fn reload_interval(&self) -> Duration {
+        self.reload_rate
+            .as_ref()
+            .and_then(|rate| parse_duration(rate).ok())
+            .unwrap_or_else(|| Duration::from_secs(constants::DEFAULT_TLS_RELOAD_RATE_IN_SECONDS))
+    }
 }
 
 impl HealthCheckConfig {
@@ -49,19 +309,201 @@ impl HealthCheckConfig {
         parse_duration(&self.timeout)
             .unwrap_or_else(|_| Duration::from_secs(constants::DEFAULT_TIMEOUT_IN_SECONDS))
     }
+
+    pub fn retries(&self) -> u32 {
+        self.retries.unwrap_or(constants::DEFAULT_PING_RETRIES)
+    }
+
+    pub fn retry_backoff_duration(&self) -> Duration {
+        self.retry_backoff
+            .as_ref()
+            .and_then(|backoff| parse_duration(backoff).ok())
+            .unwrap_or_else(|| Duration::from_secs(constants::DEFAULT_RETRY_BACKOFF_IN_SECONDS))
+    }
+
+    /// Returns the configured targets, including `ping_url` as a GET target.
+    pub fn targets(&self) -> Vec<HealthCheckTarget> {
+        let mut targets = Vec::new();
+        if let Some(ping_url) = &self.ping_url {
+            targets.push(HealthCheckTarget {
+                url: ping_url.clone(),
+                fail_url: self.fail_url.clone(),
+                method: None,
+                headers: None,
+                body: None,
+                expected_status: None,
+            });
+        }
+        targets.extend(self.targets.iter().flatten().cloned());
+        targets
+    }
+}
+
+impl HealthCheckTarget {
+    pub fn fail_url(&self) -> String {
+        self.fail_url
+            .clone()
+            .unwrap_or_else(|| format!("{}/fail", self.url.trim_end_matches('/')))
+    }
+}
+
+impl HealthCheckConditions {
+    pub fn max_block_age_duration(&self) -> Option<Duration> {
+        self.max_block_age
+            .as_ref()
+            .and_then(|age| parse_duration(age).ok())
+    }
+}
+
+impl AlertsConfig {
+    pub fn eval_interval(&self) -> Duration {
+        self.eval_rate
+            .as_ref()
+            .and_then(|rate| parse_duration(rate).ok())
+            .unwrap_or_else(|| Duration::from_secs(constants::DEFAULT_EVAL_RATE_IN_SECONDS))
+    }
+
+    pub fn timeout_duration(&self) -> Duration {
+        self.timeout
+            .as_ref()
+            .and_then(|timeout| parse_duration(timeout).ok())
+            .unwrap_or_else(|| Duration::from_secs(constants::DEFAULT_TIMEOUT_IN_SECONDS))
+    }
+}
+
+impl AlertRulesConfig {
+    pub fn max_node_lag_duration(&self) -> Option<Duration> {
+        self.max_node_lag
+            .as_ref()
+            .and_then(|lag| parse_duration(lag).ok())
+    }
+}
+
+impl BlockFollowerConfig {
+    pub fn poll_interval(&self) -> Duration {
+        parse_duration(&self.poll_rate)
+            .unwrap_or_else(|_| Duration::from_secs(constants::DEFAULT_POLL_RATE_IN_SECONDS))
+    }
+}
+
+impl ConfigArgs {
+    /// Merges the config file, `NAMADA_EXPORTER_*` environment variables and flags.
+    /// Nested fields are set with `__` in environment variables, e.g. `NAMADA_EXPORTER_HEALTHCHECK__PING_URL`,
+    /// and with dotted paths in `--set`, e.g. `--set healthcheck.ping_url=...`.
+    pub fn load(&self) -> Result<ExporterConfig, figment::Error> {
+        let mut figment = Figment::new();
+        if let Some(config) = &self.config {
+            figment = figment.merge(Toml::file_exact(config));
+        }
+        figment = figment
+            .merge(Env::prefixed(ENV_PREFIX).split("__"))
+            .merge(Serialized::defaults(self));
+        for (key, value) in &self.overrides {
+            // Values are parsed like environment variables, e.g. `true`, `100` or `["a", "b"]`
+            let value: Value = value.parse().unwrap_or_else(|e| match e {});
+            figment = figment.merge(Serialized::default(key, value));
+        }
+        figment.extract()
+    }
 }
 
 pub fn parse_cli() -> ExporterConfig {
     let cli = Cli::parse();
-    let config_content = match cli.command {
-        Commands::Start { config } => {
-            let contents =
-                fs::read_to_string(config).expect("Something went wrong reading the config file");
-            contents
+    match cli.command {
+        Commands::Start { config } => match config.load() {
+            Ok(config) => config,
+            Err(e) => {
+                eprintln!("Failed to load config: {}", e);
+                process::exit(1);
+            }
+        },
+        Commands::Validate { config } => process::exit(validate(&config)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use figment::Jail;
+    use std::env;
+
+    fn config_args(args: &[&str]) -> ConfigArgs {
+        let cli = Cli::try_parse_from(["namada-exporter", "start"].iter().chain(args)).unwrap();
+        match cli.command {
+            Commands::Start { config } => config,
+            Commands::Validate { .. } => unreachable!(),
         }
-    };
-    let config: ExporterConfig =
-        toml::from_str(&config_content).expect("Failed to parse config file");
+    }
 
-    config
+    #[test]
+    fn load_merges_file_env_flags_and_overrides_in_order() {
+        // The jail restores the environment and runs in its own directory, one jail at a time
+        Jail::expect_with(|jail| {
+            jail.create_file(
+                "namada-exporter.toml",
+                r#"
+host = "127.0.0.1:9000"
+validator_tm_address = "tnam1file"
+http_rpc = "http://file:26657"
+chain_id = "file-chain"
+collect_rate = "10s"
+expected_version = "v0.46.0"
+"#,
+            )?;
+            jail.set_env("NAMADA_EXPORTER_HTTP_RPC", "http://env:26657");
+            jail.set_env("NAMADA_EXPORTER_CHAIN_ID", "env-chain");
+            jail.set_env("NAMADA_EXPORTER_HEALTHCHECK__PING_RATE", "30s");
+            jail.set_env("NAMADA_EXPORTER_HEALTHCHECK__TIMEOUT", "5s");
+
+            let config = config_args(&[
+                "--config",
+                "namada-exporter.toml",
+                "--chain-id",
+                "flag-chain",
+                "--collect-rate",
+                "20s",
+                "--set",
+                "collect_rate=30s",
+                "--set",
+                "healthcheck.retries=3",
+            ])
+            .load()?;
+
+            // Only set in the file
+            assert_eq!(config.host, "127.0.0.1:9000");
+            assert_eq!(config.expected_version.as_deref(), Some("v0.46.0"));
+            // Environment over file
+            assert_eq!(config.http_rpc, "http://env:26657");
+            // Flag over environment
+            assert_eq!(config.chain_id.as_deref(), Some("flag-chain"));
+            // `--set` over flag
+            assert_eq!(config.collect_rate.as_deref(), Some("30s"));
+            // Nested fields from environment and `--set`
+            let healthcheck = config.healthcheck.unwrap();
+            assert_eq!(healthcheck.ping_rate, "30s");
+            assert_eq!(healthcheck.timeout, "5s");
+            assert_eq!(healthcheck.retries, Some(3));
+            Ok(())
+        });
+    }
+
+    #[test]
+    fn load_fails_on_missing_config_file() {
+        let path = env::temp_dir().join("namada-exporter-missing.toml");
+        assert!(config_args(&["--config", path.to_str().unwrap()])
+            .load()
+            .is_err());
+    }
+
+    #[test]
+    fn parse_key_value_splits_on_first_equals() {
+        assert_eq!(
+            parse_key_value("healthcheck.ping_url=https://hc-ping.com/id?a=b"),
+            Ok((
+                "healthcheck.ping_url".to_string(),
+                "https://hc-ping.com/id?a=b".to_string()
+            ))
+        );
+        assert!(parse_key_value("healthcheck.ping_url").is_err());
+    }
 }
@@ -0,0 +1,183 @@
+use crate::handlers::collect_snapshot;
+use crate::metrics::{NetworkMetricsData, NodeMetricsData, ValidatorMetricsData};
+use crate::namada_query::Query;
+use log::{debug, error, info};
+use namada_sdk::proof_of_stake::PosParams;
+use std::sync::{Arc, RwLock};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use tendermint_rpc::endpoint::status::Response as StatusResponse;
+use tokio::time::interval;
+
+/// The validator, network and node data of the latest successful collection. Unknown values are
+/// `None`, both the metrics and the status API are derived from them.
+#[derive(Debug, Clone)]
+pub struct Snapshot {
+    pub collected_at: SystemTime,
+    pub status: StatusResponse,
+    pub pos_params: PosParams,
+    pub validator: ValidatorMetricsData,
+    pub network: NetworkMetricsData,
+    pub node: NodeMetricsData,
+}
+
+impl Snapshot {
+    pub fn age(&self) -> Duration {
+        self.collected_at.elapsed().unwrap_or_default()
+    }
+
+    /// Seconds since the latest block known to the node, i.e. how far the node lags behind.
+    pub fn latest_block_age(&self) -> i64 {
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_secs() as i64)
+            .unwrap_or(0);
+        now - self.status.sync_info.latest_block_time.unix_timestamp()
+    }
+}
+
+/// Latest snapshot written by the collector only and read by the handlers, the health checker and
+/// the alerter. It is `None` until the first collection succeeds and again after a failed collection.
+pub type SharedSnapshot = Arc<RwLock<Option<Snapshot>>>;
+
+pub struct Collector {
+    q: Query,
+    validator_tm_address: String,
+    collect_interval: Duration,
+    snapshot: SharedSnapshot,
+}
+
+impl Collector {
+    pub fn new(
+        q: Query,
+        validator_tm_address: &str,
+        collect_interval: Duration,
+        snapshot: SharedSnapshot,
+    ) -> Self {
+        Self {
+            q,
+            validator_tm_address: validator_tm_address.to_string(),
+            collect_interval,
+            snapshot,
+        }
+    }
+
+    pub async fn start_collect_loop(&self) {
+        let mut interval_timer = interval(self.collect_interval);
+
+        info!(
+            "Starting collector loop, collecting every {:?}",
+            self.collect_interval
+        );
+
+        loop {
+            interval_timer.tick().await;
+            self.collect();
+        }
+    }
+
+    fn collect(&self) {
+        match collect_snapshot(&self.q, &self.validator_tm_address) {
+            Ok(snapshot) => {
+                debug!("Collected snapshot: {:?}", snapshot);
+                *self.snapshot.write().unwrap() = Some(snapshot);
+            }
+            Err(e) => {
+                error!("Collector could not collect snapshot: {}", e);
+                *self.snapshot.write().unwrap() = None;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+pub mod tests {
+    use super::*;
+    use serde_json::json;
+
+    pub const CHAIN_ID: &str = "housefire-alpaca.cc0d3e0c033be";
+    pub const VALIDATOR_HASH: &str = "A80053EA80C1B85049E3D21CA496F32AC762DA87";
+
+    /// A status response as returned by CometBFT, with the latest block `block_age` old.
+    pub fn status_response(latest_block: u64, block_age: Duration) -> StatusResponse {
+        let latest_block_time = SystemTime::now() - block_age;
+        let latest_block_time = tendermint::Time::from_unix_timestamp(
+            latest_block_time
+                .duration_since(UNIX_EPOCH)
+                .unwrap()
+                .as_secs() as i64,
+            0,
+        )
+        .unwrap()
+        .to_rfc3339();
+        serde_json::from_value(json!({
+            "node_info": {
+                "protocol_version": { "p2p": "8", "block": "11", "app": "0" },
+                "id": "e4f2b1c7a9d3e5f60718293a4b5c6d7e8f901234",
+                "listen_addr": "tcp://0.0.0.0:26656",
+                "network": CHAIN_ID,
+                "version": "0.37.11",
+                "channels": "40202122233038606100",
+                "moniker": "my-validator",
+                "other": { "tx_index": "on", "rpc_address": "tcp://0.0.0.0:26657" }
+            },
+            "sync_info": {
+                "latest_block_hash": "6A3C2F6E4D1B0A9988776655443322110FFEEDDCCBBAA9988776655443322110",
+                "latest_app_hash": "0F1E2D3C4B5A69788796A5B4C3D2E1F00F1E2D3C4B5A69788796A5B4C3D2E1F0",
+                "latest_block_height": latest_block.to_string(),
+                "latest_block_time": latest_block_time,
+                "earliest_block_hash": "6A3C2F6E4D1B0A9988776655443322110FFEEDDCCBBAA9988776655443322110",
+                "earliest_app_hash": "0F1E2D3C4B5A69788796A5B4C3D2E1F00F1E2D3C4B5A69788796A5B4C3D2E1F0",
+                "earliest_block_height": "1",
+                "earliest_block_time": "2024-12-09T15:00:00Z",
+                "catching_up": false
+            },
+            "validator_info": {
+                "address": VALIDATOR_HASH,
+                "pub_key": {
+                    "type": "tendermint/PubKeyEd25519",
+                    "value": "AQAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAA="
+                },
+                "voting_power": "1520000"
+            }
+        }))
+        .unwrap()
+    }
+
+    /// A fresh snapshot of a validator in the consensus set, ranked 12 of 257.
+    pub fn snapshot() -> Snapshot {
+        Snapshot {
+            collected_at: SystemTime::now(),
+            status: status_response(270000, Duration::from_secs(5)),
+            pos_params: PosParams::default(),
+            validator: ValidatorMetricsData {
+                namada_validator_uptime_percentage: Some(99),
+                namada_validator_state: Some(1),
+                namada_validator_active_set_rank: Some(12),
+                namada_missed_blocks: Some(3),
+                namada_total_bonds: Some(1520000.5),
+                namada_total_bonds_raw: Some(1520000500000.0),
+                namada_validator_consensus_key_mismatch: Some(false),
+                namada_validator_pending_consensus_key_epoch: None,
+                validator_commission: Some(0.05),
+                validator_address_hash: Some(VALIDATOR_HASH.to_string()),
+            },
+            network: NetworkMetricsData {
+                namada_network_epoch: 512,
+                namada_node_catch_up: 0,
+                namada_network_lowest_active_set_stake: Some(1000.0),
+                namada_network_lowest_active_set_stake_raw: Some(1000000000.0),
+                namada_network_max_set_size: 257,
+                namada_network_stake_threshold: 1.0,
+                namada_network_stake_threshold_raw: 1000000.0,
+                namada_network_active_set_size: 257,
+            },
+            node: NodeMetricsData {
+                namada_node_latest_block: 270000,
+                namada_node_is_signing_validator: 1,
+                namada_node_validator_voting_power: 1520000,
+                node_id: "e4f2b1c7a9d3e5f60718293a4b5c6d7e8f901234".to_string(),
+                moniker: "my-validator".to_string(),
+            },
+        }
+    }
+}
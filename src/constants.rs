@@ -2,3 +2,13 @@ pub const DEFAULT_METRICS_CONTENT_TYPE: &str =
     "application/openmetrics-text; version=1.0.0; charset=utf-8";
 pub const DEFAULT_PING_RATE_IN_SECONDS: u64 = 10;
 pub const DEFAULT_TIMEOUT_IN_SECONDS: u64 = 10;
+pub const DEFAULT_PING_RETRIES: u32 = 3;
+pub const DEFAULT_RETRY_BACKOFF_IN_SECONDS: u64 = 1;
+pub const DEFAULT_POLL_RATE_IN_SECONDS: u64 = 5;
+pub const DEFAULT_COLLECT_RATE_IN_SECONDS: u64 = 30;
+pub const DEFAULT_EVAL_RATE_IN_SECONDS: u64 = 30;
+pub const DEFAULT_TLS_RELOAD_RATE_IN_SECONDS: u64 = 60;
+pub const DEFAULT_PGF_RETRO_PROPOSALS: u64 = 10;
+pub const DEFAULT_IBC_PORT_ID: &str = "transfer";
+pub const AVERAGE_BLOCK_TIME_WINDOW: u64 = 100;
+pub const RECENT_VALIDATOR_EVENTS: usize = 100;
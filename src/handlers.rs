@@ -1,7 +1,34 @@
-use crate::constants::DEFAULT_METRICS_CONTENT_TYPE;
+use crate::amount::{to_denominated_f64, to_nam_f64, to_raw_f64};
+use crate::block_follower::{TxStats, ValidatorEvent};
+use crate::cli::{BalanceConfig, IbcConfig, PgfConfig, TokenConfig, UpgradeConfig};
+use crate::collector::Snapshot;
+use crate::constants::{
+    AVERAGE_BLOCK_TIME_WINDOW, DEFAULT_METRICS_CONTENT_TYPE, DEFAULT_PGF_RETRO_PROPOSALS,
+};
+use crate::healthcheck::HealthCheckStats;
+use crate::metrics::BalanceMetricsData;
+use crate::metrics::EconomicsMetricsData;
+use crate::metrics::EpochMetricsData;
+use crate::metrics::HealthCheckMetricsData;
 use crate::metrics::NetworkMetricsData;
 use crate::metrics::NodeMetricsData;
+use crate::metrics::PosParamsMetricsData;
+use crate::metrics::SlashingMetricsData;
+use crate::metrics::TokenMetricsData;
+use crate::metrics::TxMetricsData;
+use crate::metrics::UpgradeMetricsData;
 use crate::metrics::ValidatorMetricsData;
+use crate::metrics::VersionMetricsData;
+use crate::metrics::{IbcChannelMetricsData, IbcClientMetricsData, IbcMetricsData};
+use crate::metrics::{MaspMetricsData, MaspTokenMetricsData};
+use crate::metrics::{
+    PgfFundingMetricsData, PgfMetricsData, PgfRetroPaymentMetricsData, PgfStewardMetricsData,
+};
+use crate::namada_query::EconomicsData;
+use crate::namada_query::EpochProgress;
+use crate::namada_query::MaspData;
+use crate::namada_query::PgfData;
+use crate::namada_query::SlashExposure;
 use crate::namada_query::ValidatorData;
 use crate::{metrics::NamadaMetrics, namada_query::ValidatorStake};
 use axum::http::header::CONTENT_TYPE;
@@ -11,17 +38,28 @@ use axum::{
     extract::State,
     http::StatusCode,
     response::{IntoResponse, Response},
+    Json,
 };
+use namada_sdk::dec::Dec;
 use namada_sdk::proof_of_stake::PosParams;
+use namada_sdk::token::Amount;
+use tendermint_rpc::endpoint::abci_info::AbciInfo;
 use tendermint_rpc::endpoint::status::Response as StatusResponse;
 
+use crate::namada_query::Query;
 use crate::server::ServerState;
+use crate::status::{Status, STATUS_SCHEMA};
 use itertools::Itertools;
-use log::{debug, info};
+use log::{debug, error, info};
+use serde::Serialize;
+use serde_json::json;
+use std::error::Error;
+use std::time::{SystemTime, UNIX_EPOCH};
 fn process_validator_metrics_data(
     pos_params: &PosParams,
     validator_data: &ValidatorData,
-    rank: u32,
+    rank: Option<usize>,
+    response: &StatusResponse,
 ) -> ValidatorMetricsData {
     let liveness_window_check = pos_params.owned.liveness_window_check;
     let liveness_threshold: f64 = pos_params
@@ -37,107 +75,682 @@ fn process_validator_metrics_data(
     let max_block_to_slash =
         (liveness_window_check as f64) - ((liveness_window_check as f64) * liveness_threshold);
     debug!("Max block to slash: {}", max_block_to_slash);
-    let uptime_percentage = match validator_data.missed_blocks {
-        Some(missed_blocks) => {
-            let uptime = 1.0 - ((missed_blocks as f64) / max_block_to_slash);
-            uptime * 100.0
-        }
-        None => -1.0,
-    };
+    let uptime_percentage = validator_data.missed_blocks.map(|missed_blocks| {
+        let uptime = 1.0 - ((missed_blocks as f64) / max_block_to_slash);
+        (uptime * 100.0).round() as i64
+    });
     let state = validator_data.state.number();
-    let missed_blocks: i64 = validator_data.missed_blocks.map(|v| v as i64).unwrap_or(-1);
     let commission = validator_data
         .commission
         .commission_rate
-        .unwrap()
-        .to_string()
-        .parse::<f32>()
-        .unwrap_or(-1.0);
+        .and_then(|rate| rate.to_string().parse::<f64>().ok());
+    let consensus_key_mismatch = is_consensus_key_mismatch(
+        &response.validator_info.address.to_string(),
+        &validator_data.address_hash,
+        validator_data.pending_consensus_key_hash.as_deref(),
+    );
 
     ValidatorMetricsData {
-        namada_validator_uptime_percentage: uptime_percentage.round() as i64,
-        namada_validator_state: state as i64,
-        namada_validator_active_set_rank: rank as i64,
-        namada_missed_blocks: missed_blocks,
-        namada_total_bonds: validator_data.stake.parse().unwrap(),
+        namada_validator_uptime_percentage: uptime_percentage,
+        namada_validator_state: Some(state as i64),
+        namada_validator_active_set_rank: rank.map(|rank| rank as i64),
+        namada_missed_blocks: validator_data.missed_blocks.map(|missed| missed as i64),
+        namada_total_bonds: Some(to_nam_f64(validator_data.stake)),
+        namada_total_bonds_raw: Some(to_raw_f64(validator_data.stake)),
+        namada_validator_consensus_key_mismatch: Some(consensus_key_mismatch),
+        namada_validator_pending_consensus_key_epoch: validator_data
+            .pending_consensus_key_epoch
+            .map(|epoch| epoch as i64),
         validator_commission: commission,
-        validator_address_hash: validator_data.address_hash.clone(),
+        validator_address_hash: Some(validator_data.address_hash.clone()),
     }
 }
+/// A node whose key is neither the on-chain consensus key nor the pending one. Voting power is
+/// not taken into account, since a node with an unknown key has no voting power either, so
+/// sentry and other non-signing nodes with their own key always report a mismatch.
+fn is_consensus_key_mismatch(
+    node_validator_address: &str,
+    validator_address_hash: &str,
+    pending_address_hash: Option<&str>,
+) -> bool {
+    !node_validator_address.eq_ignore_ascii_case(validator_address_hash)
+        && !pending_address_hash
+            .is_some_and(|pending| node_validator_address.eq_ignore_ascii_case(pending))
+}
 fn process_network_metrics(
     epoch: &String,
     response: &StatusResponse,
     sorted_validators: Vec<&ValidatorStake>,
     pos_params: &PosParams,
 ) -> NetworkMetricsData {
-    let lowest_stake = sorted_validators.last().unwrap();
+    let lowest_stake = sorted_validators.last().map(|v| v.stake);
+    let stake_threshold = pos_params.owned.validator_stake_threshold;
     let network_metrics = NetworkMetricsData {
         namada_network_epoch: epoch.to_string().parse::<i64>().unwrap(),
         namada_node_catch_up: response.sync_info.catching_up as i64,
-        namada_network_lowest_active_set_stake: i64::try_from(lowest_stake.stake).unwrap(),
+        namada_network_lowest_active_set_stake: lowest_stake.map(to_nam_f64),
+        namada_network_lowest_active_set_stake_raw: lowest_stake.map(to_raw_f64),
         namada_network_max_set_size: pos_params.owned.max_validator_slots as i64,
-        namada_network_stake_threshold: pos_params
-            .owned
-            .validator_stake_threshold
-            .to_string()
-            .parse::<i64>()
-            .unwrap(),
+        namada_network_stake_threshold: to_nam_f64(stake_threshold),
+        namada_network_stake_threshold_raw: to_raw_f64(stake_threshold),
         namada_network_active_set_size: sorted_validators.len() as i64,
     };
     network_metrics
 }
-fn process_node_metrics(response: &StatusResponse) -> NodeMetricsData {
+fn dec_to_f64(value: &Dec) -> f64 {
+    value.to_string().parse::<f64>().unwrap_or(-1.0)
+}
+fn process_pos_params_metrics(pos_params: &PosParams) -> PosParamsMetricsData {
+    let owned = &pos_params.owned;
+    PosParamsMetricsData {
+        namada_network_pipeline_length: owned.pipeline_len as i64,
+        namada_network_unbonding_length: owned.unbonding_len as i64,
+        namada_network_slash_processing_delay: pos_params.slash_processing_epoch_offset() as i64,
+        namada_network_cubic_slashing_window_length: owned.cubic_slashing_window_length as i64,
+        namada_network_duplicate_vote_min_slash_rate: dec_to_f64(
+            &owned.duplicate_vote_min_slash_rate,
+        ),
+        namada_network_light_client_attack_min_slash_rate: dec_to_f64(
+            &owned.light_client_attack_min_slash_rate,
+        ),
+        namada_network_max_inflation_rate: dec_to_f64(&owned.max_inflation_rate),
+        namada_network_target_staked_ratio: dec_to_f64(&owned.target_staked_ratio),
+        namada_network_liveness_window: owned.liveness_window_check as i64,
+        namada_network_liveness_threshold: dec_to_f64(&owned.liveness_threshold),
+    }
+}
+fn process_epoch_metrics(
+    response: &StatusResponse,
+    epoch_progress: &EpochProgress,
+) -> EpochMetricsData {
+    let latest_block_height = response.sync_info.latest_block_height.value();
+    let latest_block_time = response.sync_info.latest_block_time.unix_timestamp();
+    epoch_metrics(latest_block_height, latest_block_time, epoch_progress)
+}
+fn epoch_metrics(
+    latest_block_height: u64,
+    latest_block_time: i64,
+    epoch_progress: &EpochProgress,
+) -> EpochMetricsData {
+    let blocks_elapsed = latest_block_height.saturating_sub(epoch_progress.first_block_height);
+    // A new epoch starts once both the minimum number of blocks and the minimum duration have passed,
+    // so the estimate is the later of the two, using the average block time of the current epoch.
+    let next_epoch_estimated_timestamp = if blocks_elapsed > 0 {
+        let average_block_time =
+            (latest_block_time - epoch_progress.first_block_time) as f64 / blocks_elapsed as f64;
+        let remaining_blocks = (epoch_progress.first_block_height
+            + epoch_progress.min_num_of_blocks)
+            .saturating_sub(latest_block_height);
+        let by_blocks = latest_block_time + (remaining_blocks as f64 * average_block_time) as i64;
+        let by_duration = epoch_progress.first_block_time + epoch_progress.min_duration as i64;
+        by_blocks.max(by_duration)
+    } else {
+        -1
+    };
+    EpochMetricsData {
+        namada_network_epoch_first_block_height: epoch_progress.first_block_height as i64,
+        namada_network_epoch_blocks_elapsed: blocks_elapsed as i64,
+        namada_network_epoch_min_blocks: epoch_progress.min_num_of_blocks as i64,
+        namada_network_epoch_min_duration_seconds: epoch_progress.min_duration as i64,
+        namada_network_next_epoch_estimated_timestamp: next_epoch_estimated_timestamp,
+    }
+}
+fn process_economics_metrics(economics: &EconomicsData) -> EconomicsMetricsData {
+    let total_supply = to_raw_f64(economics.total_supply);
+    let total_bonded_stake = to_raw_f64(economics.total_bonded_stake);
+    let staking_ratio = if total_supply > 0.0 && total_bonded_stake >= 0.0 {
+        total_bonded_stake / total_supply
+    } else {
+        -1.0
+    };
+    EconomicsMetricsData {
+        namada_network_total_supply: to_nam_f64(economics.total_supply),
+        namada_network_total_bonded_stake: to_nam_f64(economics.total_bonded_stake),
+        namada_network_staking_ratio: staking_ratio,
+        namada_network_pos_inflation_rate: dec_to_f64(&economics.pos_inflation_rate),
+        namada_network_staking_rewards_rate: dec_to_f64(&economics.staking_rewards_rate),
+        namada_network_pgf_inflation_rate: dec_to_f64(&economics.pgf_inflation_rate),
+        namada_network_total_supply_raw: total_supply,
+        namada_network_total_bonded_stake_raw: total_bonded_stake,
+    }
+}
+fn process_pgf_metrics(pgf_config: &PgfConfig, pgf: &PgfData) -> PgfMetricsData {
+    let stewards = pgf_config
+        .stewards
+        .iter()
+        .map(|address| PgfStewardMetricsData {
+            address: address.clone(),
+            namada_pgf_steward: pgf.stewards.contains(address) as i64,
+        })
+        .collect();
+    let fundings: Vec<PgfFundingMetricsData> = pgf
+        .fundings
+        .iter()
+        .map(|funding| PgfFundingMetricsData {
+            target: funding.target.clone(),
+            proposal_id: funding.proposal_id,
+            namada_pgf_continuous_funding_amount: to_nam_f64(funding.amount),
+        })
+        .collect();
+    let funding_total = fundings
+        .iter()
+        .map(|funding| funding.namada_pgf_continuous_funding_amount)
+        .sum();
+    let retro_payments: Vec<PgfRetroPaymentMetricsData> = pgf
+        .retro_payments
+        .iter()
+        .map(|payment| PgfRetroPaymentMetricsData {
+            target: payment.target.clone(),
+            proposal_id: payment.proposal_id,
+            epoch: payment.epoch,
+            namada_pgf_retro_payment_amount: to_nam_f64(payment.amount),
+        })
+        .collect();
+    let retro_payments_total = retro_payments
+        .iter()
+        .map(|payment| payment.namada_pgf_retro_payment_amount)
+        .sum();
+    PgfMetricsData {
+        stewards,
+        namada_pgf_stewards_count: pgf.stewards.len() as i64,
+        namada_pgf_continuous_fundings_count: fundings.len() as i64,
+        namada_pgf_continuous_funding_total: funding_total,
+        fundings,
+        namada_pgf_retro_payments_total: retro_payments_total,
+        retro_payments,
+    }
+}
+fn process_balance_metrics(q: &Query, balances: &[BalanceConfig]) -> Vec<BalanceMetricsData> {
+    balances
+        .iter()
+        .filter_map(
+            |balance| match q.query_balance(&balance.address, balance.token.as_deref()) {
+                Ok(token_balance) => {
+                    debug!("Queries balance: {:?}", token_balance);
+                    Some(BalanceMetricsData {
+                        address: balance.address.clone(),
+                        token: token_balance.token,
+                        alias: balance.alias.clone().unwrap_or_default(),
+                        namada_account_balance: to_denominated_f64(
+                            token_balance.amount,
+                            token_balance.denom,
+                        ),
+                        namada_account_balance_raw: to_raw_f64(token_balance.amount),
+                    })
+                }
+                Err(e) => {
+                    error!("Could not query balance of {}: {}", balance.address, e);
+                    None
+                }
+            },
+        )
+        .collect()
+}
+fn process_masp_metrics(masp: &MaspData) -> MaspMetricsData {
+    let tokens = masp
+        .tokens
+        .iter()
+        .map(|token| {
+            let rewards = token.rewards.as_ref();
+            MaspTokenMetricsData {
+                token: token.token.clone(),
+                name: token.name.clone(),
+                namada_masp_total_shielded: to_denominated_f64(token.total_shielded, token.denom),
+                namada_masp_max_reward_rate: rewards
+                    .map(|r| dec_to_f64(&r.max_reward_rate))
+                    .unwrap_or(-1.0),
+                namada_masp_kp_gain: rewards.map(|r| dec_to_f64(&r.kp_gain)).unwrap_or(-1.0),
+                namada_masp_kd_gain: rewards.map(|r| dec_to_f64(&r.kd_gain)).unwrap_or(-1.0),
+                namada_masp_locked_amount_target: rewards
+                    .map(|r| to_denominated_f64(r.locked_amount_target, token.denom))
+                    .unwrap_or(-1.0),
+            }
+        })
+        .collect();
+    MaspMetricsData {
+        tokens,
+        namada_masp_note_commitment_tree_size: masp.note_commitment_tree_size as i64,
+    }
+}
+fn process_ibc_metrics(q: &Query, ibc_config: &IbcConfig) -> IbcMetricsData {
+    let now = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs() as i64)
+        .unwrap_or_default();
+    let clients = ibc_config
+        .clients
+        .iter()
+        .flatten()
+        .filter_map(|client_id| match q.query_ibc_client(client_id) {
+            Ok(client) => {
+                debug!("Queries ibc client: {:?}", client);
+                let expires_at =
+                    client.latest_consensus_time + client.trusting_period.as_secs() as i64;
+                Some(IbcClientMetricsData {
+                    client_id: client.client_id,
+                    counterparty_chain_id: client.counterparty_chain_id,
+                    namada_ibc_client_latest_height: client.latest_height as i64,
+                    namada_ibc_client_trusting_period_remaining_seconds: expires_at - now,
+                    namada_ibc_client_frozen: client.frozen as i64,
+                })
+            }
+            Err(e) => {
+                error!("Could not query ibc client {}: {}", client_id, e);
+                None
+            }
+        })
+        .collect();
+    let channels = ibc_config
+        .channels
+        .iter()
+        .flatten()
+        .filter_map(
+            |channel| match q.query_ibc_channel(channel.port_id(), &channel.channel_id) {
+                Ok(channel) => {
+                    debug!("Queries ibc channel: {:?}", channel);
+                    Some(IbcChannelMetricsData {
+                        port_id: channel.port_id,
+                        channel_id: channel.channel_id,
+                        namada_ibc_channel_state: channel.state as i64,
+                    })
+                }
+                Err(e) => {
+                    error!("Could not query ibc channel {}: {}", channel.channel_id, e);
+                    None
+                }
+            },
+        )
+        .collect();
+    IbcMetricsData { clients, channels }
+}
+fn process_token_metrics(q: &Query, tokens: &[TokenConfig]) -> Vec<TokenMetricsData> {
+    tokens
+        .iter()
+        .filter_map(|token| match q.query_token_supply(&token.address) {
+            Ok(supply) => {
+                debug!("Queries token supply: {:?}", supply);
+                let denominated = |amount: Option<_>| {
+                    amount
+                        .map(|amount| to_denominated_f64(amount, supply.denom))
+                        .unwrap_or(-1.0)
+                };
+                Some(TokenMetricsData {
+                    token: supply.token.clone(),
+                    alias: token.alias.clone().unwrap_or_default(),
+                    namada_token_total_supply: to_denominated_f64(
+                        supply.total_supply,
+                        supply.denom,
+                    ),
+                    namada_token_denomination: supply.denom as i64,
+                    namada_token_mint_limit: denominated(supply.mint_limit),
+                    namada_token_throughput_limit: denominated(supply.throughput_limit),
+                    namada_token_minted_amount: denominated(supply.minted_amount),
+                    namada_token_total_supply_raw: to_raw_f64(supply.total_supply),
+                })
+            }
+            Err(e) => {
+                error!("Could not query token supply of {}: {}", token.address, e);
+                None
+            }
+        })
+        .collect()
+}
+fn stake_fraction(stake: Amount, total_stake: Amount) -> f64 {
+    let total_stake = to_raw_f64(total_stake);
+    if total_stake > 0.0 {
+        to_raw_f64(stake) / total_stake
+    } else {
+        0.0
+    }
+}
+fn process_slashing_metrics(
+    pos_params: &PosParams,
+    exposure: &SlashExposure,
+    address: &str,
+    validator_address_hash: &str,
+) -> SlashingMetricsData {
+    let voting_power_fraction = stake_fraction(exposure.stake, exposure.total_stake);
+    // Cubic slashing sums the voting power of every validator slashed within the window around the
+    // infraction epoch, the query already keeps one slash per validator and infraction epoch in the
+    // window. The validator itself is counted with its current voting power below.
+    let infracting_fraction: f64 = exposure
+        .enqueued_slashes
+        .iter()
+        .filter(|slash| !(slash.validator == address && slash.infraction_epoch == exposure.epoch))
+        .map(|slash| stake_fraction(slash.stake, slash.total_stake))
+        .sum();
+    let total_fraction = infracting_fraction + voting_power_fraction;
+    let cubic_slash_rate = 9.0 * total_fraction * total_fraction;
+    let slash_rate =
+        |min_slash_rate: &Dec| cubic_slash_rate.max(dec_to_f64(min_slash_rate)).min(1.0);
+    SlashingMetricsData {
+        validator_address_hash: validator_address_hash.to_string(),
+        namada_validator_voting_power_fraction: voting_power_fraction,
+        namada_validator_cubic_slash_window_infracting_fraction: infracting_fraction,
+        namada_validator_hypothetical_duplicate_vote_slash_rate: slash_rate(
+            &pos_params.owned.duplicate_vote_min_slash_rate,
+        ),
+        namada_validator_hypothetical_light_client_attack_slash_rate: slash_rate(
+            &pos_params.owned.light_client_attack_min_slash_rate,
+        ),
+        namada_network_enqueued_slashes: exposure.enqueued_slashes_total as i64,
+        namada_validator_incoming_redelegated_amount: to_nam_f64(exposure.incoming_redelegated),
+        namada_validator_outgoing_redelegated_amount: to_nam_f64(exposure.outgoing_redelegated),
+    }
+}
+fn process_node_metrics(
+    response: &StatusResponse,
+    validator_address_hash: &str,
+) -> NodeMetricsData {
+    let is_signing_validator = response
+        .validator_info
+        .address
+        .to_string()
+        .eq_ignore_ascii_case(validator_address_hash);
     NodeMetricsData {
         namada_node_latest_block: i64::from(response.sync_info.latest_block_height),
+        namada_node_validator_voting_power: response.validator_info.power.value() as i64,
+        namada_node_is_signing_validator: is_signing_validator as i64,
         node_id: response.node_info.id.to_string(),
         moniker: response.node_info.moniker.to_string(),
     }
 }
-#[debug_handler]
-pub async fn metrics_handler(State(state): State<ServerState>) -> impl IntoResponse {
-    let address = state.config.validator_tm_address.clone();
-    let q = &state.q;
-    info!("Querying metrics for validator: {}", address);
+fn process_version_metrics(
+    response: &StatusResponse,
+    abci_info: &AbciInfo,
+    expected_version: Option<&str>,
+) -> VersionMetricsData {
+    let namada_version = if abci_info.version.is_empty() {
+        "unknown".to_string()
+    } else {
+        abci_info.version.clone()
+    };
+    let version_mismatch = match expected_version {
+        Some(expected_version) => {
+            (namada_version.trim_start_matches('v') != expected_version.trim_start_matches('v'))
+                as i64
+        }
+        None => -1,
+    };
+    VersionMetricsData {
+        node_id: response.node_info.id.to_string(),
+        moniker: response.node_info.moniker.to_string(),
+        cometbft_version: response.node_info.version.to_string(),
+        namada_version,
+        protocol_version: abci_info.app_version.to_string(),
+        namada_node_version_mismatch: version_mismatch,
+    }
+}
+fn process_upgrade_metrics(
+    q: &Query,
+    response: &StatusResponse,
+    upgrade_config: &UpgradeConfig,
+) -> UpgradeMetricsData {
+    let latest_block_height = response.sync_info.latest_block_height.value();
+    let latest_block_time = response.sync_info.latest_block_time.unix_timestamp();
+    let window = AVERAGE_BLOCK_TIME_WINDOW.min(latest_block_height.saturating_sub(1));
+    let average_block_time = if window > 0 {
+        match q.query_block_time(latest_block_height - window) {
+            Ok(past_block_time) => (latest_block_time - past_block_time) as f64 / window as f64,
+            Err(e) => {
+                error!("Could not query block time: {}", e);
+                -1.0
+            }
+        }
+    } else {
+        -1.0
+    };
+    upgrade_metrics(
+        upgrade_config,
+        latest_block_height,
+        latest_block_time,
+        average_block_time,
+    )
+}
+fn upgrade_metrics(
+    upgrade_config: &UpgradeConfig,
+    latest_block_height: u64,
+    latest_block_time: i64,
+    average_block_time: f64,
+) -> UpgradeMetricsData {
+    let blocks_remaining = upgrade_config.height as i64 - latest_block_height as i64;
+    let estimated_timestamp = if average_block_time > 0.0 {
+        latest_block_time + (blocks_remaining.max(0) as f64 * average_block_time) as i64
+    } else {
+        -1
+    };
+    UpgradeMetricsData {
+        name: upgrade_config.name.clone().unwrap_or_default(),
+        namada_network_upgrade_height: upgrade_config.height as i64,
+        namada_network_upgrade_blocks_remaining: blocks_remaining,
+        namada_network_upgrade_estimated_timestamp: estimated_timestamp,
+        namada_network_average_block_time_seconds: average_block_time,
+    }
+}
+fn process_tx_metrics(tx_stats: &TxStats) -> TxMetricsData {
+    TxMetricsData {
+        namada_block_follower_height: tx_stats.last_height as i64,
+        namada_block_last_txs: tx_stats.last_block_txs as i64,
+        namada_block_last_gas_used: tx_stats.last_block_gas_used as i64,
+        namada_block_gas_limit: tx_stats.block_gas_limit as i64,
+        namada_block_processed: tx_stats.blocks,
+        namada_block_gas_used: tx_stats.gas_used,
+        namada_block_txs_succeeded: tx_stats.txs_succeeded,
+        namada_block_txs_failed: tx_stats.txs_failed,
+        txs_by_type: tx_stats
+            .txs_by_type
+            .iter()
+            .map(|(tx_type, count)| (tx_type.clone(), *count))
+            .collect(),
+        validator_events: tx_stats
+            .validator_events
+            .iter()
+            .map(|(event_type, count)| (event_type.clone(), *count))
+            .collect(),
+        validator_event_amounts: tx_stats
+            .validator_event_amounts
+            .iter()
+            .map(|(event_type, amount)| (event_type.clone(), *amount))
+            .collect(),
+    }
+}
+fn process_healthcheck_metrics(healthcheck_stats: &HealthCheckStats) -> HealthCheckMetricsData {
+    HealthCheckMetricsData {
+        namada_exporter_healthcheck_pings_succeeded: healthcheck_stats.pings_succeeded,
+        namada_exporter_healthcheck_pings_failed: healthcheck_stats.pings_failed,
+        namada_exporter_healthcheck_last_success_timestamp: healthcheck_stats
+            .last_success_timestamp,
+        namada_exporter_healthcheck_ping_latency_seconds: healthcheck_stats.latency.clone(),
+    }
+}
+/// Queries the validator, network and node data of a snapshot, run by the collector.
+pub fn collect_snapshot(q: &Query, address: &str) -> Result<Snapshot, Box<dyn Error>> {
     info!("Queryring epoch");
-    let epoch = q.query_epoch().expect("Could not query epoch");
+    let epoch = q.query_epoch()?;
     debug!("Queries epoch: {}", epoch);
     info!("Querying status");
-    let status = q.status().expect("Could not query status");
+    let status = q.status()?;
     debug!("Queries status: {:?}", status);
 
+    info!("Querying pos params");
+    let pos_params = q.query_pos_params()?;
+    debug!("Queries pos_params: {:?}", pos_params);
+
     info!("Querying validator data");
-    let validator = q.query_validators_data(&address).unwrap();
+    let validator = q.query_validators_data(address, &pos_params)?;
     debug!("Queries validator: {:?}", validator);
     info!("Querying consensus validator set");
-    let validators = q.query_consensus_validator_set().unwrap();
+    let validators = q.query_consensus_validator_set()?;
     debug!("Queries validators: {:?}", validators);
 
-    info!("Querying pos params");
-    let pos_params = q.query_pos_params().unwrap();
-    debug!("Queries pos_params: {:?}", pos_params);
-
     let sorted_validators = validators
         .iter()
         .sorted_by(|a, b| b.stake.cmp(&a.stake))
         .collect::<Vec<_>>();
-    let storted_validators_copy: Vec<&ValidatorStake> = sorted_validators.clone();
-    let validator_rank = storted_validators_copy
-        .iter() // Create an iterator over the vector
-        .position(|v| v.address == address) // Find the position of the element matching the condition
-        .expect("Can't find validator")
-        + 1;
+    // Jailed, inactive and below capacity validators are not in the consensus set and have no rank
+    let validator_rank = sorted_validators
+        .iter()
+        .position(|v| v.address == address)
+        .map(|position| position + 1);
 
     let validator_data = match validator {
-        Some(data) => process_validator_metrics_data(&pos_params, &data, validator_rank as u32),
+        Some(data) => process_validator_metrics_data(&pos_params, &data, validator_rank, &status),
         None => ValidatorMetricsData::default(),
     };
+    let network_metrics = process_network_metrics(&epoch, &status, sorted_validators, &pos_params);
+    let node_metrics = process_node_metrics(
+        &status,
+        validator_data
+            .validator_address_hash
+            .as_deref()
+            .unwrap_or_default(),
+    );
+
+    Ok(Snapshot {
+        collected_at: SystemTime::now(),
+        status,
+        pos_params,
+        validator: validator_data,
+        network: network_metrics,
+        node: node_metrics,
+    })
+}
+#[debug_handler]
+pub async fn metrics_handler(State(state): State<ServerState>) -> impl IntoResponse {
+    let address = state.config.validator_tm_address.clone();
+    let q = &state.q;
+    // The validator, network and node data come from the collector, the only writer of the snapshot
+    let Some(snapshot) = state.snapshot.read().unwrap().clone() else {
+        return Response::builder()
+            .status(StatusCode::SERVICE_UNAVAILABLE)
+            .body(Body::from(
+                "Last collection failed or no collection finished yet",
+            ))
+            .unwrap();
+    };
+    info!("Querying metrics for validator: {}", address);
+    let Snapshot {
+        status,
+        pos_params,
+        validator: validator_data,
+        network: network_metrics,
+        node: node_metrics,
+        ..
+    } = snapshot;
+
     let chain_id = status.node_info.network.to_string();
-    let metrics = NamadaMetrics::create(chain_id, state.config.validator_tm_address.clone());
+    let mut metrics = NamadaMetrics::create(
+        chain_id,
+        state.config.validator_tm_address.clone(),
+        state.config.export_raw_amounts.unwrap_or(false),
+    );
     metrics.set_validator_metrics(&validator_data);
-    let network_metrics = process_network_metrics(&epoch, &status, sorted_validators, &pos_params);
     metrics.set_network_metrics(&network_metrics);
-    let node_metrics = process_node_metrics(&status);
+    info!("Querying slash exposure");
+    match q.query_slash_exposure(&address, &pos_params) {
+        Ok(slash_exposure) => {
+            debug!("Queries slash exposure: {:?}", slash_exposure);
+            let slashing_metrics = process_slashing_metrics(
+                &pos_params,
+                &slash_exposure,
+                &address,
+                validator_data
+                    .validator_address_hash
+                    .as_deref()
+                    .unwrap_or_default(),
+            );
+            metrics.set_slashing_metrics(&slashing_metrics);
+        }
+        Err(e) => error!("Could not query slash exposure: {}", e),
+    }
+    let pos_params_metrics = process_pos_params_metrics(&pos_params);
+    metrics.set_pos_params_metrics(&pos_params_metrics);
+    info!("Querying epoch progress");
+    match q.query_epoch_progress() {
+        Ok(epoch_progress) => {
+            debug!("Queries epoch progress: {:?}", epoch_progress);
+            let epoch_metrics = process_epoch_metrics(&status, &epoch_progress);
+            metrics.set_epoch_metrics(&epoch_metrics);
+        }
+        Err(e) => error!("Could not query epoch progress: {}", e),
+    }
+    info!("Querying economics");
+    match q.query_economics() {
+        Ok(economics) => {
+            debug!("Queries economics: {:?}", economics);
+            let economics_metrics = process_economics_metrics(&economics);
+            metrics.set_economics_metrics(&economics_metrics);
+        }
+        Err(e) => error!("Could not query economics: {}", e),
+    }
+    if let Some(pgf_config) = &state.config.pgf {
+        info!("Querying pgf");
+        let retro_proposals = pgf_config
+            .retro_proposals
+            .unwrap_or(DEFAULT_PGF_RETRO_PROPOSALS);
+        match q.query_pgf(retro_proposals) {
+            Ok(pgf) => {
+                debug!("Queries pgf: {:?}", pgf);
+                let pgf_metrics = process_pgf_metrics(pgf_config, &pgf);
+                metrics.set_pgf_metrics(&pgf_metrics);
+            }
+            Err(e) => error!("Could not query pgf: {}", e),
+        }
+    }
+    if let Some(balances) = &state.config.balances {
+        info!("Querying balances");
+        let balance_metrics = process_balance_metrics(q, balances);
+        metrics.set_balance_metrics(&balance_metrics);
+    }
+    if let Some(masp_config) = &state.config.masp {
+        info!("Querying masp");
+        match q.query_masp(masp_config.tokens.as_deref().unwrap_or_default()) {
+            Ok(masp) => {
+                debug!("Queries masp: {:?}", masp);
+                let masp_metrics = process_masp_metrics(&masp);
+                metrics.set_masp_metrics(&masp_metrics);
+            }
+            Err(e) => error!("Could not query masp: {}", e),
+        }
+    }
+    if let Some(ibc_config) = &state.config.ibc {
+        info!("Querying ibc");
+        let ibc_metrics = process_ibc_metrics(q, ibc_config);
+        metrics.set_ibc_metrics(&ibc_metrics);
+    }
+    if let Some(tokens) = &state.config.tokens {
+        info!("Querying token supplies");
+        let token_metrics = process_token_metrics(q, tokens);
+        metrics.set_token_metrics(&token_metrics);
+    }
+    if let Some(upgrade_config) = &state.config.upgrade {
+        info!("Querying upgrade countdown");
+        let upgrade_metrics = process_upgrade_metrics(q, &status, upgrade_config);
+        metrics.set_upgrade_metrics(&upgrade_metrics);
+    }
+    if state.config.block_follower.is_some() {
+        let tx_metrics = process_tx_metrics(&state.tx_stats.read().unwrap());
+        metrics.set_tx_metrics(&tx_metrics);
+    }
+    if state.config.healthcheck.is_some() {
+        let healthcheck_metrics =
+            process_healthcheck_metrics(&state.healthcheck_stats.read().unwrap());
+        metrics.set_healthcheck_metrics(&healthcheck_metrics);
+    }
     metrics.set_node_metrics(&node_metrics);
+    info!("Querying abci info");
+    match q.abci_info() {
+        Ok(abci_info) => {
+            debug!("Queries abci info: {:?}", abci_info);
+            let version_metrics = process_version_metrics(
+                &status,
+                &abci_info,
+                state.config.expected_version.as_deref(),
+            );
+            metrics.set_version_metrics(&version_metrics);
+        }
+        Err(e) => error!("Could not query abci info: {}", e),
+    }
 
     let content_type = state
         .config
@@ -151,3 +764,514 @@ pub async fn metrics_handler(State(state): State<ServerState>) -> impl IntoRespo
         .body(Body::from(metrics.render()))
         .unwrap()
 }
+pub async fn validator_events_handler(State(state): State<ServerState>) -> impl IntoResponse {
+    let events: Vec<ValidatorEvent> = state
+        .tx_stats
+        .read()
+        .unwrap()
+        .recent_validator_events
+        .iter()
+        .cloned()
+        .collect();
+    Json(events)
+}
+
+#[derive(Serialize)]
+pub struct DependencyStatus {
+    pub healthy: bool,
+    pub message: String,
+}
+
+#[derive(Serialize)]
+pub struct Dependencies {
+    pub collector: DependencyStatus,
+    pub rpc: DependencyStatus,
+}
+
+#[derive(Serialize)]
+pub struct ReadinessResponse {
+    pub ready: bool,
+    pub dependencies: Dependencies,
+}
+
+pub async fn healthz_handler() -> impl IntoResponse {
+    Json(json!({ "alive": true }))
+}
+
+pub async fn readyz_handler(State(state): State<ServerState>) -> impl IntoResponse {
+    let freshness_window = state.config.freshness_window();
+    let collector = match state.snapshot.read().unwrap().as_ref() {
+        Some(snapshot) => DependencyStatus {
+            healthy: snapshot.age() <= freshness_window,
+            message: format!(
+                "last successful collection {}s ago, freshness window {}s",
+                snapshot.age().as_secs(),
+                freshness_window.as_secs()
+            ),
+        },
+        None => DependencyStatus {
+            healthy: false,
+            message: "last collection failed or no collection finished yet".to_string(),
+        },
+    };
+    let rpc = match state.q.status() {
+        Ok(status) => DependencyStatus {
+            healthy: true,
+            message: format!(
+                "reachable, latest block {}",
+                status.sync_info.latest_block_height
+            ),
+        },
+        Err(e) => {
+            error!("Readiness check could not query status: {}", e);
+            DependencyStatus {
+                healthy: false,
+                message: format!("unreachable: {}", e),
+            }
+        }
+    };
+    let ready = collector.healthy && rpc.healthy;
+    let status_code = if ready {
+        StatusCode::OK
+    } else {
+        StatusCode::SERVICE_UNAVAILABLE
+    };
+    (
+        status_code,
+        Json(ReadinessResponse {
+            ready,
+            dependencies: Dependencies { collector, rpc },
+        }),
+    )
+}
+
+pub async fn status_handler(State(state): State<ServerState>) -> impl IntoResponse {
+    match state.snapshot.read().unwrap().as_ref() {
+        Some(snapshot) => (
+            StatusCode::OK,
+            Json(json!(Status::from_snapshot(
+                snapshot,
+                &state.config.validator_tm_address
+            ))),
+        ),
+        None => (
+            StatusCode::SERVICE_UNAVAILABLE,
+            Json(json!({ "error": "last collection failed or no collection finished yet" })),
+        ),
+    }
+}
+
+pub async fn status_schema_handler() -> impl IntoResponse {
+    ([(CONTENT_TYPE, "application/schema+json")], STATUS_SCHEMA)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::collector::tests::snapshot;
+    use crate::namada_query::{EnqueuedSlash, PgfFunding, PgfRetroPayment};
+    use figment::providers::{Format, Toml};
+    use figment::Figment;
+    use namada_sdk::token::Amount;
+    use std::str::FromStr;
+    use std::sync::{Arc, RwLock};
+
+    fn epoch_progress() -> EpochProgress {
+        EpochProgress {
+            first_block_height: 1000,
+            first_block_time: 1_700_000_000,
+            min_num_of_blocks: 100,
+            min_duration: 600,
+        }
+    }
+
+    #[test]
+    fn epoch_eta_is_unknown_on_the_first_block() {
+        let metrics = epoch_metrics(1000, 1_700_000_000, &epoch_progress());
+        assert_eq!(metrics.namada_network_epoch_blocks_elapsed, 0);
+        assert_eq!(metrics.namada_network_next_epoch_estimated_timestamp, -1);
+    }
+
+    #[test]
+    fn epoch_eta_waits_for_remaining_blocks() {
+        // 50 blocks in 500s, 10s per block, so the remaining 50 blocks take another 500s
+        let metrics = epoch_metrics(1050, 1_700_000_500, &epoch_progress());
+        assert_eq!(metrics.namada_network_epoch_first_block_height, 1000);
+        assert_eq!(metrics.namada_network_epoch_blocks_elapsed, 50);
+        assert_eq!(metrics.namada_network_epoch_min_blocks, 100);
+        assert_eq!(metrics.namada_network_epoch_min_duration_seconds, 600);
+        assert_eq!(
+            metrics.namada_network_next_epoch_estimated_timestamp,
+            1_700_001_000
+        );
+    }
+
+    #[test]
+    fn epoch_eta_waits_for_min_duration() {
+        // 50 blocks in 100s, 2s per block, the remaining 50 blocks are done before the 600s passed
+        let metrics = epoch_metrics(1050, 1_700_000_100, &epoch_progress());
+        assert_eq!(
+            metrics.namada_network_next_epoch_estimated_timestamp,
+            1_700_000_600
+        );
+    }
+
+    #[test]
+    fn epoch_eta_after_min_blocks_is_min_duration_or_now() {
+        let metrics = epoch_metrics(1150, 1_700_000_300, &epoch_progress());
+        assert_eq!(
+            metrics.namada_network_next_epoch_estimated_timestamp,
+            1_700_000_600
+        );
+        let metrics = epoch_metrics(1150, 1_700_000_900, &epoch_progress());
+        assert_eq!(
+            metrics.namada_network_next_epoch_estimated_timestamp,
+            1_700_000_900
+        );
+    }
+
+    fn economics(total_supply: u64, total_bonded_stake: u64) -> EconomicsData {
+        EconomicsData {
+            total_supply: Amount::from_uint(total_supply, 0).unwrap(),
+            total_bonded_stake: Amount::from_uint(total_bonded_stake, 0).unwrap(),
+            pos_inflation_rate: Dec::from_str("0.05").unwrap(),
+            staking_rewards_rate: Dec::from_str("0.125").unwrap(),
+            pgf_inflation_rate: Dec::from_str("0.01").unwrap(),
+        }
+    }
+
+    #[test]
+    fn economics_metrics_in_nam_with_staking_ratio() {
+        let metrics = process_economics_metrics(&economics(1_000_000_000_000, 400_000_000_000));
+        assert_eq!(metrics.namada_network_total_supply, 1_000_000.0);
+        assert_eq!(metrics.namada_network_total_bonded_stake, 400_000.0);
+        assert_eq!(metrics.namada_network_total_supply_raw, 1_000_000_000_000.0);
+        assert_eq!(
+            metrics.namada_network_total_bonded_stake_raw,
+            400_000_000_000.0
+        );
+        assert_eq!(metrics.namada_network_staking_ratio, 0.4);
+        assert_eq!(metrics.namada_network_pos_inflation_rate, 0.05);
+        assert_eq!(metrics.namada_network_staking_rewards_rate, 0.125);
+        assert_eq!(metrics.namada_network_pgf_inflation_rate, 0.01);
+    }
+
+    #[test]
+    fn staking_ratio_is_unknown_without_supply() {
+        let metrics = process_economics_metrics(&economics(0, 0));
+        assert_eq!(metrics.namada_network_staking_ratio, -1.0);
+    }
+
+    const STEWARD: &str = "tnam1qxgzrwqn9qny9fzd7xnlrdkf7hhj9ecyx5mv3sgw";
+    const OTHER_STEWARD: &str = "tnam1q9rhgyv3ydq0zu3whnftvllqnvhvhm270qxay5tn";
+
+    fn funding(target: &str, proposal_id: u64, amount: u64) -> PgfFunding {
+        PgfFunding {
+            target: target.to_string(),
+            proposal_id,
+            amount: Amount::from_uint(amount, 0).unwrap(),
+        }
+    }
+
+    #[test]
+    fn pgf_metrics_flag_configured_stewards_and_sum_fundings() {
+        let pgf_config = PgfConfig {
+            stewards: vec![STEWARD.to_string(), OTHER_STEWARD.to_string()],
+            retro_proposals: None,
+        };
+        let pgf = PgfData {
+            stewards: vec![STEWARD.to_string()],
+            fundings: vec![
+                funding(STEWARD, 3, 1_500_000),
+                funding(OTHER_STEWARD, 7, 250_000),
+            ],
+            retro_payments: vec![],
+        };
+        let metrics = process_pgf_metrics(&pgf_config, &pgf);
+        let stewards: Vec<_> = metrics
+            .stewards
+            .iter()
+            .map(|steward| (steward.address.as_str(), steward.namada_pgf_steward))
+            .collect();
+        assert_eq!(stewards, vec![(STEWARD, 1), (OTHER_STEWARD, 0)]);
+        assert_eq!(metrics.namada_pgf_stewards_count, 1);
+        assert_eq!(metrics.namada_pgf_continuous_fundings_count, 2);
+        assert_eq!(metrics.namada_pgf_continuous_funding_total, 1.75);
+        assert_eq!(metrics.fundings[1].proposal_id, 7);
+        assert_eq!(
+            metrics.fundings[1].namada_pgf_continuous_funding_amount,
+            0.25
+        );
+        assert!(metrics.retro_payments.is_empty());
+        assert_eq!(metrics.namada_pgf_retro_payments_total, 0.0);
+    }
+
+    #[test]
+    fn pgf_metrics_sum_retro_payments() {
+        let pgf_config = PgfConfig {
+            stewards: vec![],
+            retro_proposals: Some(5),
+        };
+        let retro_payment = |proposal_id: u64, amount: u64| PgfRetroPayment {
+            target: STEWARD.to_string(),
+            proposal_id,
+            epoch: 100 + proposal_id,
+            amount: Amount::from_uint(amount, 0).unwrap(),
+        };
+        let pgf = PgfData {
+            stewards: vec![],
+            fundings: vec![],
+            retro_payments: vec![retro_payment(4, 10_000_000), retro_payment(9, 2_500_000)],
+        };
+        let metrics = process_pgf_metrics(&pgf_config, &pgf);
+        assert_eq!(metrics.namada_pgf_retro_payments_total, 12.5);
+        assert_eq!(metrics.retro_payments[1].proposal_id, 9);
+        assert_eq!(metrics.retro_payments[1].epoch, 109);
+        assert_eq!(
+            metrics.retro_payments[1].namada_pgf_retro_payment_amount,
+            2.5
+        );
+    }
+
+    fn upgrade_config(height: u64) -> UpgradeConfig {
+        UpgradeConfig {
+            height,
+            name: Some("v1.1.0".to_string()),
+        }
+    }
+
+    #[test]
+    fn upgrade_eta_from_average_block_time() {
+        let metrics = upgrade_metrics(&upgrade_config(2000), 1500, 1_700_000_000, 6.5);
+        assert_eq!(metrics.name, "v1.1.0");
+        assert_eq!(metrics.namada_network_upgrade_height, 2000);
+        assert_eq!(metrics.namada_network_upgrade_blocks_remaining, 500);
+        assert_eq!(
+            metrics.namada_network_upgrade_estimated_timestamp,
+            1_700_003_250
+        );
+        assert_eq!(metrics.namada_network_average_block_time_seconds, 6.5);
+    }
+
+    #[test]
+    fn upgrade_eta_is_now_once_the_height_is_reached() {
+        let metrics = upgrade_metrics(&upgrade_config(2000), 2010, 1_700_000_000, 6.5);
+        assert_eq!(metrics.namada_network_upgrade_blocks_remaining, -10);
+        assert_eq!(
+            metrics.namada_network_upgrade_estimated_timestamp,
+            1_700_000_000
+        );
+    }
+
+    #[test]
+    fn upgrade_eta_is_unknown_without_average_block_time() {
+        let metrics = upgrade_metrics(&upgrade_config(2000), 1500, 1_700_000_000, -1.0);
+        assert_eq!(metrics.namada_network_upgrade_blocks_remaining, 500);
+        assert_eq!(metrics.namada_network_upgrade_estimated_timestamp, -1);
+    }
+
+    const VALIDATOR_HASH: &str = "A80053EA80C1B85049E3D21CA496F32AC762DA87";
+    const OTHER_HASH: &str = "5A7E0B3C8F1D2E4A6B9C0D1E2F3A4B5C6D7E8F90";
+
+    const PENDING_HASH: &str = "0F1E2D3C4B5A69788796A5B4C3D2E1F00F1E2D3C";
+
+    #[test]
+    fn consensus_key_mismatch_compares_node_key_with_consensus_key() {
+        assert!(is_consensus_key_mismatch(OTHER_HASH, VALIDATOR_HASH, None));
+        assert!(!is_consensus_key_mismatch(
+            &VALIDATOR_HASH.to_lowercase(),
+            VALIDATOR_HASH,
+            None
+        ));
+    }
+
+    #[test]
+    fn consensus_key_mismatch_accepts_the_pending_key() {
+        // A node rotated ahead of the pending key activation has no voting power yet
+        assert!(!is_consensus_key_mismatch(
+            PENDING_HASH,
+            VALIDATOR_HASH,
+            Some(PENDING_HASH)
+        ));
+        assert!(!is_consensus_key_mismatch(
+            VALIDATOR_HASH,
+            VALIDATOR_HASH,
+            Some(PENDING_HASH)
+        ));
+    }
+
+    #[test]
+    fn consensus_key_mismatch_flags_rotated_key_without_voting_power() {
+        // A node rotated to a key the chain doesn't know gets no voting power
+        assert!(is_consensus_key_mismatch(
+            OTHER_HASH,
+            VALIDATOR_HASH,
+            Some(PENDING_HASH)
+        ));
+    }
+
+    const VALIDATOR: &str = "tnam1q8unq87chw62cmn6j2v7xfwr52xre06rxq6x4yuq";
+    const OTHER_VALIDATOR: &str = "tnam1q9rhgyv3ydq0zu3whnftvllqnvhvhm270qxay5tn";
+
+    fn slashing_pos_params() -> PosParams {
+        let mut pos_params = PosParams::default();
+        pos_params.owned.duplicate_vote_min_slash_rate = Dec::from_str("0.01").unwrap();
+        pos_params.owned.light_client_attack_min_slash_rate = Dec::from_str("0.05").unwrap();
+        pos_params
+    }
+
+    fn enqueued_slash(validator: &str, infraction_epoch: u64, stake: u64) -> EnqueuedSlash {
+        EnqueuedSlash {
+            validator: validator.to_string(),
+            infraction_epoch,
+            stake: Amount::from_u64(stake),
+            total_stake: Amount::from_u64(100),
+        }
+    }
+
+    fn slash_exposure(stake: u64, enqueued_slashes: Vec<EnqueuedSlash>) -> SlashExposure {
+        SlashExposure {
+            epoch: 10,
+            stake: Amount::from_u64(stake),
+            total_stake: Amount::from_u64(100),
+            enqueued_slashes_total: enqueued_slashes.len() + 1,
+            enqueued_slashes,
+            incoming_redelegated: Amount::native_whole(250),
+            outgoing_redelegated: Amount::native_whole(40),
+        }
+    }
+
+    fn assert_close(actual: f64, expected: f64) {
+        assert!(
+            (actual - expected).abs() < 1e-9,
+            "{} != {}",
+            actual,
+            expected
+        );
+    }
+
+    #[test]
+    fn cubic_slash_rate_is_nine_times_the_squared_fraction() {
+        let exposure = slash_exposure(10, vec![enqueued_slash(OTHER_VALIDATOR, 9, 10)]);
+        let metrics =
+            process_slashing_metrics(&slashing_pos_params(), &exposure, VALIDATOR, VALIDATOR_HASH);
+        assert_close(metrics.namada_validator_voting_power_fraction, 0.1);
+        assert_close(
+            metrics.namada_validator_cubic_slash_window_infracting_fraction,
+            0.1,
+        );
+        // 9 * (0.1 + 0.1)^2
+        assert_close(
+            metrics.namada_validator_hypothetical_duplicate_vote_slash_rate,
+            0.36,
+        );
+        assert_close(
+            metrics.namada_validator_hypothetical_light_client_attack_slash_rate,
+            0.36,
+        );
+        assert_eq!(metrics.namada_network_enqueued_slashes, 2);
+        assert_close(metrics.namada_validator_incoming_redelegated_amount, 250.0);
+        assert_close(metrics.namada_validator_outgoing_redelegated_amount, 40.0);
+    }
+
+    #[test]
+    fn slash_rate_is_floored_at_the_min_slash_rate() {
+        // 9 * 0.02^2 = 0.0036 is below both min slash rates
+        let metrics = process_slashing_metrics(
+            &slashing_pos_params(),
+            &slash_exposure(2, vec![]),
+            VALIDATOR,
+            VALIDATOR_HASH,
+        );
+        assert_close(
+            metrics.namada_validator_hypothetical_duplicate_vote_slash_rate,
+            0.01,
+        );
+        assert_close(
+            metrics.namada_validator_hypothetical_light_client_attack_slash_rate,
+            0.05,
+        );
+    }
+
+    #[test]
+    fn slash_rate_is_capped_at_one() {
+        // 9 * (0.3 + 0.1)^2 = 1.44
+        let exposure = slash_exposure(30, vec![enqueued_slash(OTHER_VALIDATOR, 11, 10)]);
+        let metrics =
+            process_slashing_metrics(&slashing_pos_params(), &exposure, VALIDATOR, VALIDATOR_HASH);
+        assert_close(
+            metrics.namada_validator_hypothetical_duplicate_vote_slash_rate,
+            1.0,
+        );
+        assert_close(
+            metrics.namada_validator_hypothetical_light_client_attack_slash_rate,
+            1.0,
+        );
+    }
+
+    #[test]
+    fn own_slash_in_the_current_epoch_is_counted_once() {
+        let exposure = slash_exposure(
+            10,
+            vec![
+                enqueued_slash(VALIDATOR, 10, 10),
+                enqueued_slash(VALIDATOR, 9, 10),
+            ],
+        );
+        let metrics =
+            process_slashing_metrics(&slashing_pos_params(), &exposure, VALIDATOR, VALIDATOR_HASH);
+        assert_close(
+            metrics.namada_validator_cubic_slash_window_infracting_fraction,
+            0.1,
+        );
+        assert_close(
+            metrics.namada_validator_hypothetical_duplicate_vote_slash_rate,
+            0.36,
+        );
+    }
+
+    fn server_state(snapshot: Option<Snapshot>) -> ServerState {
+        let config = Figment::from(Toml::string(
+            r#"
+host = "127.0.0.1:3001"
+validator_tm_address = "tnam1q9rhgyv3ydq0zu3whnftvllqnvhvhm270qxay5tn"
+http_rpc = "http://127.0.0.1:9"
+"#,
+        ))
+        .extract()
+        .unwrap();
+        ServerState {
+            q: Query::create("http://127.0.0.1:9").unwrap(),
+            config,
+            tx_stats: Default::default(),
+            snapshot: Arc::new(RwLock::new(snapshot)),
+            healthcheck_stats: Default::default(),
+        }
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn metrics_are_unavailable_without_snapshot() {
+        let response = metrics_handler(State(server_state(None)))
+            .await
+            .into_response();
+        assert_eq!(response.status(), StatusCode::SERVICE_UNAVAILABLE);
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn metrics_are_rendered_from_the_collector_snapshot() {
+        let snapshot = snapshot();
+        let collected_at = snapshot.collected_at;
+        let state = server_state(Some(snapshot));
+        // The RPC is unreachable, so only the snapshot metrics can be rendered
+        let response = metrics_handler(State(state.clone())).await.into_response();
+        assert_eq!(response.status(), StatusCode::OK);
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let body = String::from_utf8(body.to_vec()).unwrap();
+        assert!(body.contains("namada_validator_active_set_rank"));
+        // A scrape neither replaces nor clears the snapshot of the collector
+        let snapshot = state.snapshot.read().unwrap();
+        assert_eq!(snapshot.as_ref().unwrap().collected_at, collected_at);
+    }
+}
@@ -1,29 +1,132 @@
-use crate::cli::HealthCheckConfig;
+use crate::cli::{HealthCheckConditions, HealthCheckConfig, HealthCheckTarget};
+use crate::collector::SharedSnapshot;
+use itertools::Itertools;
 use log::{error, info, warn};
-use reqwest::Client;
-use tokio::time::interval;
+use prometheus_client::metrics::histogram::{exponential_buckets, Histogram};
+use reqwest::{Client, Method, StatusCode};
+use serde_json::Value;
+use std::sync::{Arc, RwLock};
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+use tokio::time::{interval, sleep};
+
+/// `ValidatorState::ActiveConsensusSet` as exported by `namada_validator_state`
+const CONSENSUS_STATE: i64 = 1;
+
+/// Status details available to target URLs and bodies as `{placeholder}`s.
+struct PingStatus {
+    up: bool,
+    message: String,
+    latest_block: i64,
+    validator_state: i64,
+    missed_blocks: i64,
+}
+
+impl PingStatus {
+    /// Fills the placeholders of a URL template, percent-encoding the values.
+    fn render_url(&self, template: &str) -> String {
+        self.render(template, percent_encode)
+    }
+
+    /// Fills the placeholders of a body template, escaping the values for JSON strings.
+    fn render_body(&self, template: &str) -> String {
+        self.render(template, json_escape)
+    }
+
+    fn render(&self, template: &str, escape: fn(&str) -> String) -> String {
+        template
+            .replace("{status}", &escape(if self.up { "up" } else { "down" }))
+            .replace("{message}", &escape(&self.message))
+            .replace("{latest_block}", &escape(&self.latest_block.to_string()))
+            .replace(
+                "{validator_state}",
+                &escape(&self.validator_state.to_string()),
+            )
+            .replace("{missed_blocks}", &escape(&self.missed_blocks.to_string()))
+    }
+}
+
+/// Percent-encodes everything but the unreserved characters of RFC 3986.
+fn percent_encode(value: &str) -> String {
+    value
+        .bytes()
+        .map(|byte| match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~' => {
+                (byte as char).to_string()
+            }
+            _ => format!("%{:02X}", byte),
+        })
+        .collect()
+}
+
+/// Escapes a value for use inside a JSON string, without the surrounding quotes.
+fn json_escape(value: &str) -> String {
+    let quoted = Value::String(value.to_string()).to_string();
+    quoted[1..quoted.len() - 1].to_string()
+}
+
+#[derive(Debug, Clone)]
+pub struct HealthCheckStats {
+    pub pings_succeeded: u64,
+    pub pings_failed: u64,
+    pub last_success_timestamp: i64,
+    /// Latency of every ping attempt, including retries
+    pub latency: Histogram,
+}
+
+impl Default for HealthCheckStats {
+    fn default() -> Self {
+        Self {
+            pings_succeeded: 0,
+            pings_failed: 0,
+            last_success_timestamp: -1,
+            // 10ms up to ~20s
+            latency: Histogram::new(exponential_buckets(0.01, 2.0, 12)),
+        }
+    }
+}
 
 pub struct HealthChecker {
     client: Client,
     config: HealthCheckConfig,
+    targets: Vec<HealthCheckTarget>,
+    snapshot: SharedSnapshot,
+    max_snapshot_age: Duration,
+    stats: Arc<RwLock<HealthCheckStats>>,
 }
 
 impl HealthChecker {
-    pub fn new(config: HealthCheckConfig) -> Self {
+    pub fn new(
+        config: HealthCheckConfig,
+        snapshot: SharedSnapshot,
+        max_snapshot_age: Duration,
+        stats: Arc<RwLock<HealthCheckStats>>,
+    ) -> Self {
         let client = Client::builder()
             .timeout(config.timeout_duration())
             .build()
             .expect("Failed to create HTTP client for health checks");
 
-        Self { client, config }
+        Self {
+            client,
+            targets: config.targets(),
+            config,
+            snapshot,
+            max_snapshot_age,
+            stats,
+        }
     }
 
     pub async fn start_health_check_loop(&self) {
+        if self.targets.is_empty() {
+            warn!("Health check enabled without ping_url or targets, nothing to ping");
+            return;
+        }
         let mut interval_timer = interval(self.config.ping_interval());
 
         info!(
             "Starting health check loop, pinging {} every {}",
-            self.config.ping_url, self.config.ping_rate
+            self.targets.iter().map(|target| &target.url).join(", "),
+            self.config.ping_rate
         );
 
         loop {
@@ -33,20 +136,440 @@ impl HealthChecker {
     }
 
     async fn ping(&self) {
-        match self.client.get(&self.config.ping_url).send().await {
-            Ok(response) => {
-                if response.status().is_success() {
-                    info!("Health check ping successful: {}", response.status());
-                } else {
+        let status = self.status();
+        if !status.up {
+            warn!("Health check conditions failed: {}", status.message);
+        }
+        for target in &self.targets {
+            self.ping_target(target, &status).await;
+        }
+    }
+
+    /// Pings a target, retrying failed attempts with exponential backoff.
+    async fn ping_target(&self, target: &HealthCheckTarget, status: &PingStatus) {
+        let url = if status.up {
+            status.render_url(&target.url)
+        } else {
+            status.render_url(&target.fail_url())
+        };
+        let retries = self.config.retries();
+        let mut backoff = self.config.retry_backoff_duration();
+        for attempt in 0..=retries {
+            if attempt > 0 {
+                sleep(backoff).await;
+                backoff *= 2;
+            }
+            let start = Instant::now();
+            let result = self.send(target, &url, status).await;
+            self.stats
+                .read()
+                .unwrap()
+                .latency
+                .observe(start.elapsed().as_secs_f64());
+            match result {
+                Ok(status_code) => {
+                    info!("Health check ping to {} successful: {}", url, status_code);
+                    let mut stats = self.stats.write().unwrap();
+                    stats.pings_succeeded += 1;
+                    stats.last_success_timestamp = SystemTime::now()
+                        .duration_since(UNIX_EPOCH)
+                        .map(|d| d.as_secs() as i64)
+                        .unwrap_or(-1);
+                    return;
+                }
+                Err(e) => {
                     warn!(
-                        "Health check ping returned non-success status: {}",
-                        response.status()
+                        "Health check ping to {} failed (attempt {}/{}): {}",
+                        url,
+                        attempt + 1,
+                        retries + 1,
+                        e
                     );
                 }
             }
-            Err(e) => {
-                error!("Health check ping failed: {}", e);
+        }
+        error!(
+            "Health check ping to {} failed after {} attempts",
+            url,
+            retries + 1
+        );
+        self.stats.write().unwrap().pings_failed += 1;
+    }
+
+    async fn send(
+        &self,
+        target: &HealthCheckTarget,
+        url: &str,
+        status: &PingStatus,
+    ) -> Result<StatusCode, String> {
+        let mut request = self.client.request(method(target), url);
+        for (name, value) in target.headers.iter().flatten() {
+            request = request.header(name, value);
+        }
+        if let Some(body) = &target.body {
+            request = request.body(status.render_body(body));
+        }
+        let response = request.send().await.map_err(|e| e.to_string())?;
+        if is_expected_status(target, response.status()) {
+            Ok(response.status())
+        } else {
+            Err(format!("unexpected status {}", response.status()))
+        }
+    }
+
+    fn status(&self) -> PingStatus {
+        let failures = match &self.config.conditions {
+            Some(conditions) => self.check_conditions(conditions),
+            None => Vec::new(),
+        };
+        let snapshot = self.snapshot.read().unwrap();
+        PingStatus {
+            up: failures.is_empty(),
+            message: if failures.is_empty() {
+                "OK".to_string()
+            } else {
+                failures.join(", ")
+            },
+            latest_block: snapshot
+                .as_ref()
+                .map(|s| s.node.namada_node_latest_block)
+                .unwrap_or(-1),
+            validator_state: snapshot
+                .as_ref()
+                .and_then(|s| s.validator.namada_validator_state)
+                .unwrap_or(-1),
+            missed_blocks: snapshot
+                .as_ref()
+                .and_then(|s| s.validator.namada_missed_blocks)
+                .unwrap_or(-1),
+        }
+    }
+
+    /// Evaluates the configured conditions against the latest snapshot and returns the failed ones.
+    fn check_conditions(&self, conditions: &HealthCheckConditions) -> Vec<String> {
+        let snapshot = self.snapshot.read().unwrap();
+        let Some(snapshot) = snapshot.as_ref() else {
+            return vec!["no snapshot collected".to_string()];
+        };
+        if snapshot.age() > self.max_snapshot_age {
+            return vec![format!(
+                "snapshot is stale ({}s old)",
+                snapshot.age().as_secs()
+            )];
+        }
+        let mut failures = Vec::new();
+        if conditions.not_catching_up.unwrap_or(false) && snapshot.status.sync_info.catching_up {
+            failures.push("node is catching up".to_string());
+        }
+        if let Some(max_block_age) = conditions.max_block_age_duration() {
+            let block_age = snapshot.latest_block_age();
+            if block_age > max_block_age.as_secs() as i64 {
+                failures.push(format!(
+                    "block height {} has not advanced for {}s",
+                    snapshot.node.namada_node_latest_block, block_age
+                ));
             }
         }
+        if conditions.consensus_state.unwrap_or(false) {
+            match snapshot.validator.namada_validator_state {
+                Some(CONSENSUS_STATE) => {}
+                Some(state) => {
+                    failures.push(format!("validator state is {} instead of consensus", state))
+                }
+                None => failures.push("validator state is unknown".to_string()),
+            }
+        }
+        if let Some(max_missed_blocks) = conditions.max_missed_blocks {
+            match snapshot.validator.namada_missed_blocks {
+                Some(missed_blocks) if missed_blocks < max_missed_blocks => {}
+                Some(missed_blocks) => failures.push(format!(
+                    "missed blocks {} not below {}",
+                    missed_blocks, max_missed_blocks
+                )),
+                None => failures.push("missed blocks are unknown".to_string()),
+            }
+        }
+        failures
+    }
+}
+
+fn method(target: &HealthCheckTarget) -> Method {
+    match target.method.as_deref().map(str::to_uppercase).as_deref() {
+        None | Some("GET") => Method::GET,
+        Some("POST") => Method::POST,
+        Some("HEAD") => Method::HEAD,
+        Some(other) => {
+            warn!(
+                "Unsupported health check method {} for {}, using GET",
+                other, target.url
+            );
+            Method::GET
+        }
+    }
+}
+
+fn is_expected_status(target: &HealthCheckTarget, status: StatusCode) -> bool {
+    match &target.expected_status {
+        Some(expected_status) => expected_status.contains(&status.as_u16()),
+        None => status.is_success(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::collector::tests::snapshot;
+    use crate::collector::Snapshot;
+    use axum::routing::any;
+    use axum::Router;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use tokio::net::TcpListener;
+
+    fn config(conditions: HealthCheckConditions) -> HealthCheckConfig {
+        HealthCheckConfig {
+            ping_url: Some("https://hc-ping.com/your-unique-id".to_string()),
+            ping_rate: "10s".to_string(),
+            timeout: "5s".to_string(),
+            fail_url: None,
+            retries: None,
+            retry_backoff: None,
+            targets: None,
+            conditions: Some(conditions),
+        }
+    }
+
+    fn all_conditions() -> HealthCheckConditions {
+        HealthCheckConditions {
+            not_catching_up: Some(true),
+            max_block_age: Some("60s".to_string()),
+            consensus_state: Some(true),
+            max_missed_blocks: Some(100),
+        }
+    }
+
+    fn failures(snapshot: Option<Snapshot>) -> Vec<String> {
+        let conditions = all_conditions();
+        let health_checker = HealthChecker::new(
+            config(conditions.clone()),
+            Arc::new(RwLock::new(snapshot)),
+            Duration::from_secs(90),
+            Arc::default(),
+        );
+        health_checker.check_conditions(&conditions)
+    }
+
+    #[test]
+    fn conditions_pass_for_healthy_validator() {
+        assert!(failures(Some(snapshot())).is_empty());
+    }
+
+    #[test]
+    fn conditions_fail_without_recent_snapshot() {
+        assert_eq!(failures(None), ["no snapshot collected"]);
+
+        let mut stale = snapshot();
+        stale.collected_at = SystemTime::now() - Duration::from_secs(300);
+        assert_eq!(failures(Some(stale)), ["snapshot is stale (300s old)"]);
+    }
+
+    #[test]
+    fn conditions_report_every_failure() {
+        let mut snapshot = snapshot();
+        snapshot.status =
+            crate::collector::tests::status_response(270000, Duration::from_secs(600));
+        snapshot.status.sync_info.catching_up = true;
+        snapshot.validator.namada_missed_blocks = Some(150);
+        let failures = failures(Some(snapshot));
+        assert_eq!(failures.len(), 3);
+        assert_eq!(failures[0], "node is catching up");
+        assert!(failures[1].starts_with("block height 270000 has not advanced for"));
+        assert_eq!(failures[2], "missed blocks 150 not below 100");
+    }
+
+    #[test]
+    fn consensus_state_fails_for_jailed_validator() {
+        let mut jailed = snapshot();
+        jailed.validator.namada_validator_state = Some(4);
+        jailed.validator.namada_validator_active_set_rank = None;
+        assert_eq!(
+            failures(Some(jailed)),
+            ["validator state is 4 instead of consensus"]
+        );
+    }
+
+    #[test]
+    fn unknown_missed_blocks_fail() {
+        let mut snapshot = snapshot();
+        snapshot.validator.namada_missed_blocks = None;
+        assert_eq!(failures(Some(snapshot)), ["missed blocks are unknown"]);
+    }
+
+    fn down_status(message: &str) -> PingStatus {
+        PingStatus {
+            up: false,
+            message: message.to_string(),
+            latest_block: 270000,
+            validator_state: 4,
+            missed_blocks: -1,
+        }
+    }
+
+    #[test]
+    fn render_url_percent_encodes_placeholders() {
+        let status = down_status("validator state is 4 & \"jailed\"");
+        assert_eq!(
+            status.render_url("https://uptime.example.com/push?status={status}&msg={message}&h={latest_block}"),
+            "https://uptime.example.com/push?status=down&msg=validator%20state%20is%204%20%26%20%22jailed%22&h=270000"
+        );
+    }
+
+    #[test]
+    fn render_body_escapes_json_strings() {
+        let status = down_status("node said \"no\"\\\nbye");
+        let body = status.render_body(
+            r#"{"status": "{status}", "message": "{message}", "height": {latest_block}, "missed": {missed_blocks}}"#,
+        );
+        let json: Value = serde_json::from_str(&body).unwrap();
+        assert_eq!(json["status"], "down");
+        assert_eq!(json["message"], "node said \"no\"\\\nbye");
+        assert_eq!(json["height"], 270000);
+        assert_eq!(json["missed"], -1);
+    }
+
+    #[test]
+    fn render_keeps_templates_without_placeholders() {
+        let status = down_status("OK");
+        assert_eq!(
+            status.render_url("https://hc-ping.com/your-unique-id/fail"),
+            "https://hc-ping.com/your-unique-id/fail"
+        );
+    }
+
+    fn target(method: Option<&str>, expected_status: Option<Vec<u16>>) -> HealthCheckTarget {
+        HealthCheckTarget {
+            url: "https://monitoring.example.com/heartbeat/".to_string(),
+            fail_url: None,
+            method: method.map(str::to_string),
+            headers: None,
+            body: None,
+            expected_status,
+        }
+    }
+
+    #[test]
+    fn method_defaults_to_get() {
+        assert_eq!(method(&target(None, None)), Method::GET);
+        assert_eq!(method(&target(Some("post"), None)), Method::POST);
+        assert_eq!(method(&target(Some("HEAD"), None)), Method::HEAD);
+        assert_eq!(method(&target(Some("DELETE"), None)), Method::GET);
+    }
+
+    #[test]
+    fn expected_status_defaults_to_success() {
+        let any_success = target(None, None);
+        assert!(is_expected_status(&any_success, StatusCode::OK));
+        assert!(is_expected_status(&any_success, StatusCode::NO_CONTENT));
+        assert!(!is_expected_status(&any_success, StatusCode::FOUND));
+
+        let accepted_only = target(None, Some(vec![202]));
+        assert!(is_expected_status(&accepted_only, StatusCode::ACCEPTED));
+        assert!(!is_expected_status(&accepted_only, StatusCode::OK));
+    }
+
+    #[test]
+    fn targets_include_ping_url_with_fail_url_default() {
+        let mut config = config(all_conditions());
+        config.targets = Some(vec![target(Some("POST"), None)]);
+        let targets = config.targets();
+        assert_eq!(targets.len(), 2);
+        assert_eq!(
+            targets[0].fail_url(),
+            "https://hc-ping.com/your-unique-id/fail"
+        );
+        assert_eq!(
+            targets[1].fail_url(),
+            "https://monitoring.example.com/heartbeat/fail"
+        );
+    }
+
+    /// Starts a local HTTP server answering the first `failures` requests with 500, then 200.
+    async fn flaky_server(failures: usize) -> (String, Arc<AtomicUsize>) {
+        let requests = Arc::new(AtomicUsize::new(0));
+        let counter = requests.clone();
+        let app = Router::new().route(
+            "/*path",
+            any(move || {
+                let counter = counter.clone();
+                async move {
+                    if counter.fetch_add(1, Ordering::SeqCst) < failures {
+                        StatusCode::INTERNAL_SERVER_ERROR
+                    } else {
+                        StatusCode::OK
+                    }
+                }
+            }),
+        );
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("http://{}/ping", listener.local_addr().unwrap());
+        tokio::spawn(async move {
+            axum::serve(listener, app).await.unwrap();
+        });
+        (url, requests)
+    }
+
+    fn retrying_health_checker(url: &str) -> HealthChecker {
+        let mut config = config(all_conditions());
+        config.ping_url = Some(url.to_string());
+        config.retries = Some(2);
+        config.retry_backoff = Some("20ms".to_string());
+        HealthChecker::new(
+            config,
+            Arc::new(RwLock::new(Some(snapshot()))),
+            Duration::from_secs(90),
+            Arc::default(),
+        )
+    }
+
+    fn up_status() -> PingStatus {
+        PingStatus {
+            up: true,
+            message: "OK".to_string(),
+            latest_block: 270000,
+            validator_state: 1,
+            missed_blocks: 3,
+        }
+    }
+
+    #[tokio::test]
+    async fn ping_retries_with_exponential_backoff() {
+        let (url, requests) = flaky_server(2).await;
+        let health_checker = retrying_health_checker(&url);
+        let start = Instant::now();
+        health_checker
+            .ping_target(&health_checker.targets[0], &up_status())
+            .await;
+
+        assert_eq!(requests.load(Ordering::SeqCst), 3);
+        // 20ms before the first retry, 40ms before the second
+        assert!(start.elapsed() >= Duration::from_millis(60));
+        let stats = health_checker.stats.read().unwrap();
+        assert_eq!(stats.pings_succeeded, 1);
+        assert_eq!(stats.pings_failed, 0);
+        assert!(stats.last_success_timestamp > 0);
+    }
+
+    #[tokio::test]
+    async fn ping_fails_after_all_retries() {
+        let (url, requests) = flaky_server(usize::MAX).await;
+        let health_checker = retrying_health_checker(&url);
+        health_checker
+            .ping_target(&health_checker.targets[0], &up_status())
+            .await;
+
+        assert_eq!(requests.load(Ordering::SeqCst), 3);
+        let stats = health_checker.stats.read().unwrap();
+        assert_eq!(stats.pings_succeeded, 0);
+        assert_eq!(stats.pings_failed, 1);
+        assert_eq!(stats.last_success_timestamp, -1);
     }
 }
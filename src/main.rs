@@ -1,16 +1,28 @@
 use namada_query::Query;
 use server::start_server;
+mod alerts;
+mod amount;
+mod auth;
+mod block_follower;
 mod cli;
+mod collector;
 mod constants;
 mod handlers;
 mod healthcheck;
 mod metrics;
 mod namada_query;
 mod server;
+mod status;
+mod tls;
+mod validate;
+use alerts::Alerter;
+use block_follower::{BlockFollower, TxStats};
 use cli::parse_cli;
+use collector::Collector;
 use env_logger::{Builder, Env};
-use healthcheck::HealthChecker;
+use healthcheck::{HealthCheckStats, HealthChecker};
 use log::info;
+use std::sync::{Arc, RwLock};
 const LOG_ENV_VAR: &str = "RUST_LOG";
 
 #[tokio::main]
@@ -20,14 +32,61 @@ async fn main() {
     let env = Env::default().filter_or(LOG_ENV_VAR, "info");
     Builder::from_env(env).init();
 
+    // Start collector refreshing the latest snapshot
+    let snapshot = Arc::new(RwLock::new(None));
+    let collector = Collector::new(
+        q.clone(),
+        &exporter_config.validator_tm_address,
+        exporter_config.collect_interval(),
+        snapshot.clone(),
+    );
+    tokio::spawn(async move {
+        collector.start_collect_loop().await;
+    });
+
     // Start health checker if configured
+    let healthcheck_stats = Arc::new(RwLock::new(HealthCheckStats::default()));
     if let Some(hc_config) = exporter_config.healthcheck.clone() {
         info!("Health check enabled with config: {:?}", hc_config);
-        let health_checker = HealthChecker::new(hc_config);
+        let health_checker = HealthChecker::new(
+            hc_config,
+            snapshot.clone(),
+            exporter_config.freshness_window(),
+            healthcheck_stats.clone(),
+        );
         tokio::spawn(async move {
             health_checker.start_health_check_loop().await;
         });
     }
 
-    start_server(&exporter_config, q).await;
+    // Start alerts if configured
+    if let Some(alerts_config) = exporter_config.alerts.clone() {
+        info!("Alerts enabled with rules: {:?}", alerts_config.rules);
+        let mut alerter = Alerter::new(
+            alerts_config,
+            &exporter_config.validator_tm_address,
+            snapshot.clone(),
+            exporter_config.freshness_window(),
+        );
+        tokio::spawn(async move {
+            alerter.start_alert_loop().await;
+        });
+    }
+
+    // Start block follower if configured
+    let tx_stats = Arc::new(RwLock::new(TxStats::default()));
+    if let Some(bf_config) = exporter_config.block_follower.clone() {
+        info!("Block follower enabled with config: {:?}", bf_config);
+        let block_follower = BlockFollower::new(
+            bf_config,
+            q.clone(),
+            &exporter_config.validator_tm_address,
+            tx_stats.clone(),
+        );
+        tokio::spawn(async move {
+            block_follower.start_follow_loop().await;
+        });
+    }
+
+    start_server(&exporter_config, q, tx_stats, snapshot, healthcheck_stats).await;
 }
@@ -1,7 +1,9 @@
 use prometheus_client::encoding::text::encode;
 use prometheus_client::encoding::EncodeLabelSet;
+use prometheus_client::metrics::counter::Counter;
 use prometheus_client::metrics::family::Family;
 use prometheus_client::metrics::gauge::Gauge;
+use prometheus_client::metrics::histogram::Histogram;
 use prometheus_client::registry::Registry;
 use std::sync::atomic::AtomicU64;
 
@@ -21,44 +23,268 @@ struct NodeLabels {
     node_id: String,
     moniker: String,
 }
-
-#[derive(Debug)]
-pub struct ValidatorMetricsData {
-    pub namada_validator_uptime_percentage: i64,
-    pub namada_validator_state: i64,
-    pub namada_validator_active_set_rank: i64,
-    pub namada_missed_blocks: i64,
-    pub namada_total_bonds: i64,
-    pub validator_commission: f32,
-    pub validator_address_hash: String,
+#[derive(Clone, Debug, Hash, PartialEq, Eq, EncodeLabelSet)]
+struct PgfStewardLabels {
+    chain_id: String,
+    address: String,
+}
+#[derive(Clone, Debug, Hash, PartialEq, Eq, EncodeLabelSet)]
+struct PgfFundingLabels {
+    chain_id: String,
+    target: String,
+    proposal_id: String,
+}
+#[derive(Clone, Debug, Hash, PartialEq, Eq, EncodeLabelSet)]
+struct PgfRetroPaymentLabels {
+    chain_id: String,
+    target: String,
+    proposal_id: String,
+    epoch: String,
+}
+#[derive(Clone, Debug, Hash, PartialEq, Eq, EncodeLabelSet)]
+struct BalanceLabels {
+    chain_id: String,
+    address: String,
+    token: String,
+    alias: String,
+}
+#[derive(Clone, Debug, Hash, PartialEq, Eq, EncodeLabelSet)]
+struct MaspTokenLabels {
+    chain_id: String,
+    token: String,
+    name: String,
+}
+#[derive(Clone, Debug, Hash, PartialEq, Eq, EncodeLabelSet)]
+struct IbcClientLabels {
+    chain_id: String,
+    client_id: String,
+    counterparty_chain_id: String,
+}
+#[derive(Clone, Debug, Hash, PartialEq, Eq, EncodeLabelSet)]
+struct IbcChannelLabels {
+    chain_id: String,
+    port_id: String,
+    channel_id: String,
+}
+#[derive(Clone, Debug, Hash, PartialEq, Eq, EncodeLabelSet)]
+struct TokenLabels {
+    chain_id: String,
+    token: String,
+    alias: String,
+}
+#[derive(Clone, Debug, Hash, PartialEq, Eq, EncodeLabelSet)]
+struct VersionLabels {
+    chain_id: String,
+    node_id: String,
+    moniker: String,
+    cometbft_version: String,
+    namada_version: String,
+    protocol_version: String,
+}
+#[derive(Clone, Debug, Hash, PartialEq, Eq, EncodeLabelSet)]
+struct UpgradeLabels {
+    chain_id: String,
+    name: String,
+}
+#[derive(Clone, Debug, Hash, PartialEq, Eq, EncodeLabelSet)]
+struct TxResultLabels {
+    chain_id: String,
+    result: String,
+}
+#[derive(Clone, Debug, Hash, PartialEq, Eq, EncodeLabelSet)]
+struct PingResultLabels {
+    chain_id: String,
+    result: String,
+}
+#[derive(Clone, Debug, Hash, PartialEq, Eq, EncodeLabelSet)]
+struct TxTypeLabels {
+    chain_id: String,
+    tx_type: String,
+}
+#[derive(Clone, Debug, Hash, PartialEq, Eq, EncodeLabelSet)]
+struct ValidatorEventLabels {
+    chain_id: String,
+    validator_tm_address: String,
+    event_type: String,
 }
 
-impl ValidatorMetricsData {
-    pub fn default() -> Self {
-        ValidatorMetricsData {
-            namada_validator_uptime_percentage: -1,
-            namada_validator_state: -1,
-            namada_validator_active_set_rank: -1,
-            namada_missed_blocks: -1,
-            namada_total_bonds: -1,
-            validator_commission: 0.0,
-            validator_address_hash: "".to_string(),
-        }
-    }
+/// Validator data of a snapshot, `None` where a value is unknown, e.g. when the validator is not
+/// found. Unknown values are exported as -1.
+#[derive(Debug, Clone, Default)]
+pub struct ValidatorMetricsData {
+    pub namada_validator_uptime_percentage: Option<i64>,
+    pub namada_validator_state: Option<i64>,
+    pub namada_validator_active_set_rank: Option<i64>,
+    pub namada_missed_blocks: Option<i64>,
+    pub namada_total_bonds: Option<f64>,
+    pub namada_total_bonds_raw: Option<f64>,
+    pub namada_validator_consensus_key_mismatch: Option<bool>,
+    pub namada_validator_pending_consensus_key_epoch: Option<i64>,
+    pub validator_commission: Option<f64>,
+    pub validator_address_hash: Option<String>,
 }
 
+#[derive(Debug, Clone)]
 pub struct NetworkMetricsData {
     pub namada_network_epoch: i64,
     pub namada_node_catch_up: i64,
-    pub namada_network_lowest_active_set_stake: i64,
+    pub namada_network_lowest_active_set_stake: Option<f64>,
+    pub namada_network_lowest_active_set_stake_raw: Option<f64>,
     pub namada_network_max_set_size: i64,
-    pub namada_network_stake_threshold: i64,
+    pub namada_network_stake_threshold: f64,
+    pub namada_network_stake_threshold_raw: f64,
     pub namada_network_active_set_size: i64,
 }
+#[derive(Debug, Clone)]
 pub struct NodeMetricsData {
     pub namada_node_latest_block: i64,
+    pub namada_node_is_signing_validator: i64,
+    pub namada_node_validator_voting_power: i64,
+    pub node_id: String,
+    pub moniker: String,
+}
+pub struct PosParamsMetricsData {
+    pub namada_network_pipeline_length: i64,
+    pub namada_network_unbonding_length: i64,
+    pub namada_network_slash_processing_delay: i64,
+    pub namada_network_cubic_slashing_window_length: i64,
+    pub namada_network_duplicate_vote_min_slash_rate: f64,
+    pub namada_network_light_client_attack_min_slash_rate: f64,
+    pub namada_network_max_inflation_rate: f64,
+    pub namada_network_target_staked_ratio: f64,
+    pub namada_network_liveness_window: i64,
+    pub namada_network_liveness_threshold: f64,
+}
+pub struct EpochMetricsData {
+    pub namada_network_epoch_first_block_height: i64,
+    pub namada_network_epoch_blocks_elapsed: i64,
+    pub namada_network_epoch_min_blocks: i64,
+    pub namada_network_epoch_min_duration_seconds: i64,
+    pub namada_network_next_epoch_estimated_timestamp: i64,
+}
+pub struct EconomicsMetricsData {
+    pub namada_network_total_supply: f64,
+    pub namada_network_total_bonded_stake: f64,
+    pub namada_network_staking_ratio: f64,
+    pub namada_network_pos_inflation_rate: f64,
+    pub namada_network_staking_rewards_rate: f64,
+    pub namada_network_pgf_inflation_rate: f64,
+    pub namada_network_total_supply_raw: f64,
+    pub namada_network_total_bonded_stake_raw: f64,
+}
+pub struct PgfStewardMetricsData {
+    pub address: String,
+    pub namada_pgf_steward: i64,
+}
+pub struct PgfFundingMetricsData {
+    pub target: String,
+    pub proposal_id: u64,
+    pub namada_pgf_continuous_funding_amount: f64,
+}
+pub struct PgfRetroPaymentMetricsData {
+    pub target: String,
+    pub proposal_id: u64,
+    pub epoch: u64,
+    pub namada_pgf_retro_payment_amount: f64,
+}
+pub struct PgfMetricsData {
+    pub stewards: Vec<PgfStewardMetricsData>,
+    pub fundings: Vec<PgfFundingMetricsData>,
+    pub retro_payments: Vec<PgfRetroPaymentMetricsData>,
+    pub namada_pgf_stewards_count: i64,
+    pub namada_pgf_continuous_fundings_count: i64,
+    pub namada_pgf_continuous_funding_total: f64,
+    pub namada_pgf_retro_payments_total: f64,
+}
+pub struct BalanceMetricsData {
+    pub address: String,
+    pub token: String,
+    pub alias: String,
+    pub namada_account_balance: f64,
+    pub namada_account_balance_raw: f64,
+}
+pub struct MaspTokenMetricsData {
+    pub token: String,
+    pub name: String,
+    pub namada_masp_total_shielded: f64,
+    pub namada_masp_max_reward_rate: f64,
+    pub namada_masp_kp_gain: f64,
+    pub namada_masp_kd_gain: f64,
+    pub namada_masp_locked_amount_target: f64,
+}
+pub struct MaspMetricsData {
+    pub tokens: Vec<MaspTokenMetricsData>,
+    pub namada_masp_note_commitment_tree_size: i64,
+}
+pub struct IbcClientMetricsData {
+    pub client_id: String,
+    pub counterparty_chain_id: String,
+    pub namada_ibc_client_latest_height: i64,
+    pub namada_ibc_client_trusting_period_remaining_seconds: i64,
+    pub namada_ibc_client_frozen: i64,
+}
+pub struct IbcChannelMetricsData {
+    pub port_id: String,
+    pub channel_id: String,
+    pub namada_ibc_channel_state: i64,
+}
+pub struct IbcMetricsData {
+    pub clients: Vec<IbcClientMetricsData>,
+    pub channels: Vec<IbcChannelMetricsData>,
+}
+pub struct TokenMetricsData {
+    pub token: String,
+    pub alias: String,
+    pub namada_token_total_supply: f64,
+    pub namada_token_denomination: i64,
+    pub namada_token_mint_limit: f64,
+    pub namada_token_throughput_limit: f64,
+    pub namada_token_minted_amount: f64,
+    pub namada_token_total_supply_raw: f64,
+}
+pub struct VersionMetricsData {
     pub node_id: String,
     pub moniker: String,
+    pub cometbft_version: String,
+    pub namada_version: String,
+    pub protocol_version: String,
+    pub namada_node_version_mismatch: i64,
+}
+pub struct UpgradeMetricsData {
+    pub name: String,
+    pub namada_network_upgrade_height: i64,
+    pub namada_network_upgrade_blocks_remaining: i64,
+    pub namada_network_upgrade_estimated_timestamp: i64,
+    pub namada_network_average_block_time_seconds: f64,
+}
+pub struct TxMetricsData {
+    pub namada_block_follower_height: i64,
+    pub namada_block_last_txs: i64,
+    pub namada_block_last_gas_used: i64,
+    pub namada_block_gas_limit: i64,
+    pub namada_block_processed: u64,
+    pub namada_block_gas_used: u64,
+    pub namada_block_txs_succeeded: u64,
+    pub namada_block_txs_failed: u64,
+    pub txs_by_type: Vec<(String, u64)>,
+    pub validator_events: Vec<(String, u64)>,
+    pub validator_event_amounts: Vec<(String, f64)>,
+}
+pub struct SlashingMetricsData {
+    pub validator_address_hash: String,
+    pub namada_validator_voting_power_fraction: f64,
+    pub namada_validator_cubic_slash_window_infracting_fraction: f64,
+    pub namada_validator_hypothetical_duplicate_vote_slash_rate: f64,
+    pub namada_validator_hypothetical_light_client_attack_slash_rate: f64,
+    pub namada_network_enqueued_slashes: i64,
+    pub namada_validator_incoming_redelegated_amount: f64,
+    pub namada_validator_outgoing_redelegated_amount: f64,
+}
+pub struct HealthCheckMetricsData {
+    pub namada_exporter_healthcheck_pings_succeeded: u64,
+    pub namada_exporter_healthcheck_pings_failed: u64,
+    pub namada_exporter_healthcheck_last_success_timestamp: i64,
+    pub namada_exporter_healthcheck_ping_latency_seconds: Histogram,
 }
 pub struct ValidatorMetricInt {
     name: String,
@@ -77,27 +303,215 @@ pub struct NetworkMetricInt {
     metric: Family<NetworkLabels, Gauge>,
 }
 
+pub struct NetworkMetricFloat {
+    name: String,
+    help: String,
+    metric: Family<NetworkLabels, Gauge<f64, AtomicU64>>,
+}
+
 pub struct NodeMetricInt {
     name: String,
     help: String,
     metric: Family<NodeLabels, Gauge>,
 }
 
+pub struct PgfStewardMetricInt {
+    name: String,
+    help: String,
+    metric: Family<PgfStewardLabels, Gauge>,
+}
+
+pub struct PgfFundingMetricFloat {
+    name: String,
+    help: String,
+    metric: Family<PgfFundingLabels, Gauge<f64, AtomicU64>>,
+}
+
+pub struct PgfRetroPaymentMetricFloat {
+    name: String,
+    help: String,
+    metric: Family<PgfRetroPaymentLabels, Gauge<f64, AtomicU64>>,
+}
+
+pub struct BalanceMetricFloat {
+    name: String,
+    help: String,
+    metric: Family<BalanceLabels, Gauge<f64, AtomicU64>>,
+}
+
+pub struct MaspTokenMetricFloat {
+    name: String,
+    help: String,
+    metric: Family<MaspTokenLabels, Gauge<f64, AtomicU64>>,
+}
+
+pub struct IbcClientMetricInt {
+    name: String,
+    help: String,
+    metric: Family<IbcClientLabels, Gauge>,
+}
+
+pub struct IbcChannelMetricInt {
+    name: String,
+    help: String,
+    metric: Family<IbcChannelLabels, Gauge>,
+}
+
+pub struct TokenMetricInt {
+    name: String,
+    help: String,
+    metric: Family<TokenLabels, Gauge>,
+}
+
+pub struct TokenMetricFloat {
+    name: String,
+    help: String,
+    metric: Family<TokenLabels, Gauge<f64, AtomicU64>>,
+}
+
+pub struct VersionMetricInt {
+    name: String,
+    help: String,
+    metric: Family<VersionLabels, Gauge>,
+}
+
+pub struct UpgradeMetricInt {
+    name: String,
+    help: String,
+    metric: Family<UpgradeLabels, Gauge>,
+}
+
+pub struct NetworkMetricCounter {
+    name: String,
+    help: String,
+    metric: Family<NetworkLabels, Counter>,
+}
+
+pub struct TxResultMetricCounter {
+    name: String,
+    help: String,
+    metric: Family<TxResultLabels, Counter>,
+}
+
+pub struct PingResultMetricCounter {
+    name: String,
+    help: String,
+    metric: Family<PingResultLabels, Counter>,
+}
+
+pub struct TxTypeMetricCounter {
+    name: String,
+    help: String,
+    metric: Family<TxTypeLabels, Counter>,
+}
+
+pub struct ValidatorEventMetricCounter {
+    name: String,
+    help: String,
+    metric: Family<ValidatorEventLabels, Counter>,
+}
+
+pub struct ValidatorEventMetricFloatCounter {
+    name: String,
+    help: String,
+    metric: Family<ValidatorEventLabels, Counter<f64, AtomicU64>>,
+}
+
 pub struct Metrics {
     namada_validator_uptime_percentage: ValidatorMetricInt,
     namada_validator_state: ValidatorMetricInt,
     namada_validator_active_set_rank: ValidatorMetricInt,
     namada_missed_blocks: ValidatorMetricInt,
-    namada_total_bonds: ValidatorMetricInt,
+    namada_total_bonds: ValidatorMetricFloat,
+    namada_total_bonds_raw: ValidatorMetricFloat,
+    namada_validator_consensus_key_mismatch: ValidatorMetricInt,
+    namada_validator_pending_consensus_key_epoch: ValidatorMetricInt,
     validator_commission: ValidatorMetricFloat,
     namada_network_epoch: NetworkMetricInt,
     namada_node_catch_up: NetworkMetricInt,
-    namada_network_lowest_active_set_stake: NetworkMetricInt,
+    namada_network_lowest_active_set_stake: NetworkMetricFloat,
+    namada_network_lowest_active_set_stake_raw: NetworkMetricFloat,
     namada_network_max_set_size: NetworkMetricInt,
-    namada_network_stake_threshold: NetworkMetricInt,
+    namada_network_stake_threshold: NetworkMetricFloat,
+    namada_network_stake_threshold_raw: NetworkMetricFloat,
     namada_network_active_set_size: NetworkMetricInt,
     namada_node_latest_block: NodeMetricInt,
+    namada_node_validator_voting_power: NodeMetricInt,
+    namada_node_is_signing_validator: NodeMetricInt,
     namada_validator_missed_blocks: NetworkMetricInt,
+    namada_network_pipeline_length: NetworkMetricInt,
+    namada_network_unbonding_length: NetworkMetricInt,
+    namada_network_slash_processing_delay: NetworkMetricInt,
+    namada_network_cubic_slashing_window_length: NetworkMetricInt,
+    namada_network_duplicate_vote_min_slash_rate: NetworkMetricFloat,
+    namada_network_light_client_attack_min_slash_rate: NetworkMetricFloat,
+    namada_network_max_inflation_rate: NetworkMetricFloat,
+    namada_network_target_staked_ratio: NetworkMetricFloat,
+    namada_network_liveness_window: NetworkMetricInt,
+    namada_network_liveness_threshold: NetworkMetricFloat,
+    namada_network_epoch_first_block_height: NetworkMetricInt,
+    namada_network_epoch_blocks_elapsed: NetworkMetricInt,
+    namada_network_epoch_min_blocks: NetworkMetricInt,
+    namada_network_epoch_min_duration_seconds: NetworkMetricInt,
+    namada_network_next_epoch_estimated_timestamp: NetworkMetricInt,
+    namada_network_total_supply: NetworkMetricFloat,
+    namada_network_total_bonded_stake: NetworkMetricFloat,
+    namada_network_staking_ratio: NetworkMetricFloat,
+    namada_network_pos_inflation_rate: NetworkMetricFloat,
+    namada_network_staking_rewards_rate: NetworkMetricFloat,
+    namada_network_pgf_inflation_rate: NetworkMetricFloat,
+    namada_network_total_supply_raw: NetworkMetricFloat,
+    namada_network_total_bonded_stake_raw: NetworkMetricFloat,
+    namada_pgf_steward: PgfStewardMetricInt,
+    namada_pgf_continuous_funding_amount: PgfFundingMetricFloat,
+    namada_pgf_stewards_count: NetworkMetricInt,
+    namada_pgf_continuous_fundings_count: NetworkMetricInt,
+    namada_pgf_continuous_funding_total: NetworkMetricFloat,
+    namada_pgf_retro_payment_amount: PgfRetroPaymentMetricFloat,
+    namada_pgf_retro_payments_total: NetworkMetricFloat,
+    namada_account_balance: BalanceMetricFloat,
+    namada_account_balance_raw: BalanceMetricFloat,
+    namada_masp_total_shielded: MaspTokenMetricFloat,
+    namada_masp_max_reward_rate: MaspTokenMetricFloat,
+    namada_masp_kp_gain: MaspTokenMetricFloat,
+    namada_masp_kd_gain: MaspTokenMetricFloat,
+    namada_masp_locked_amount_target: MaspTokenMetricFloat,
+    namada_masp_note_commitment_tree_size: NetworkMetricInt,
+    namada_ibc_client_latest_height: IbcClientMetricInt,
+    namada_ibc_client_trusting_period_remaining_seconds: IbcClientMetricInt,
+    namada_ibc_client_frozen: IbcClientMetricInt,
+    namada_ibc_channel_state: IbcChannelMetricInt,
+    namada_token_total_supply: TokenMetricFloat,
+    namada_token_denomination: TokenMetricInt,
+    namada_token_mint_limit: TokenMetricFloat,
+    namada_token_throughput_limit: TokenMetricFloat,
+    namada_token_minted_amount: TokenMetricFloat,
+    namada_token_total_supply_raw: TokenMetricFloat,
+    namada_node_version_info: VersionMetricInt,
+    namada_node_version_mismatch: NodeMetricInt,
+    namada_network_upgrade_height: UpgradeMetricInt,
+    namada_network_upgrade_blocks_remaining: UpgradeMetricInt,
+    namada_network_upgrade_estimated_timestamp: UpgradeMetricInt,
+    namada_network_average_block_time_seconds: NetworkMetricFloat,
+    namada_block_follower_height: NetworkMetricInt,
+    namada_block_last_txs: NetworkMetricInt,
+    namada_block_last_gas_used: NetworkMetricInt,
+    namada_block_gas_limit: NetworkMetricInt,
+    namada_block_processed: NetworkMetricCounter,
+    namada_block_gas_used: NetworkMetricCounter,
+    namada_block_txs: TxResultMetricCounter,
+    namada_block_inner_txs: TxTypeMetricCounter,
+    namada_validator_tx_events: ValidatorEventMetricCounter,
+    namada_validator_tx_event_amount: ValidatorEventMetricFloatCounter,
+    namada_validator_voting_power_fraction: ValidatorMetricFloat,
+    namada_validator_cubic_slash_window_infracting_fraction: ValidatorMetricFloat,
+    namada_validator_hypothetical_duplicate_vote_slash_rate: ValidatorMetricFloat,
+    namada_validator_hypothetical_light_client_attack_slash_rate: ValidatorMetricFloat,
+    namada_network_enqueued_slashes: NetworkMetricInt,
+    namada_validator_incoming_redelegated_amount: ValidatorMetricFloat,
+    namada_validator_outgoing_redelegated_amount: ValidatorMetricFloat,
+    namada_exporter_healthcheck_pings: PingResultMetricCounter,
+    namada_exporter_healthcheck_last_success_timestamp: NetworkMetricInt,
 }
 pub struct NamadaMetrics {
     chain_id: String,
@@ -106,7 +520,7 @@ pub struct NamadaMetrics {
     metrics: Metrics,
 }
 impl NamadaMetrics {
-    pub fn create(chain_id: String, tm_address: String) -> Self {
+    pub fn create(chain_id: String, tm_address: String, export_raw_amounts: bool) -> Self {
         let mut registry = Registry::default();
         let metric = Metrics {
             namada_validator_uptime_percentage: ValidatorMetricInt {
@@ -129,9 +543,24 @@ impl NamadaMetrics {
                 help: "Validator missed blocks in liveness window; -1 value if not in active set".to_string(),
                 metric: Family::<ValidatorLabels, Gauge>::default(),
             },
-            namada_total_bonds: ValidatorMetricInt {
+            namada_total_bonds: ValidatorMetricFloat {
                 name: "namada_validator_total_bonds".to_string(),
-                help: "Validator total bonds".to_string(),
+                help: "Validator total bonds in NAM".to_string(),
+                metric: Family::<ValidatorLabels, Gauge::<f64, AtomicU64>>::default(),
+            },
+            namada_total_bonds_raw: ValidatorMetricFloat {
+                name: "namada_validator_total_bonds_raw".to_string(),
+                help: "Validator total bonds in raw (micro) units".to_string(),
+                metric: Family::<ValidatorLabels, Gauge::<f64, AtomicU64>>::default(),
+            },
+            namada_validator_consensus_key_mismatch: ValidatorMetricInt {
+                name: "namada_validator_consensus_key_mismatch".to_string(),
+                help: "Local node uses a key other than the validator on-chain consensus key or its pending key; 0 - no mismatch, 1 - mismatch, always 1 on sentry and other non-signing nodes".to_string(),
+                metric: Family::<ValidatorLabels, Gauge>::default(),
+            },
+            namada_validator_pending_consensus_key_epoch: ValidatorMetricInt {
+                name: "namada_validator_pending_consensus_key_epoch".to_string(),
+                help: "Epoch at which a pending consensus key change takes effect; -1 value if there is no pending change".to_string(),
                 metric: Family::<ValidatorLabels, Gauge>::default(),
             },
             validator_commission: ValidatorMetricFloat {
@@ -149,26 +578,46 @@ impl NamadaMetrics {
                 help: "Validator catch up status; 0 - not catching up, 1 - catching up".to_string(),
                 metric: Family::<NetworkLabels, Gauge>::default(),
             },
-            namada_network_lowest_active_set_stake: NetworkMetricInt {
+            namada_network_lowest_active_set_stake: NetworkMetricFloat {
                 name: "namada_network_lowest_active_set_stake".to_string(),
-                help: "Lowest active set stake".to_string(),
-                metric: Family::<NetworkLabels, Gauge>::default(),
+                help: "Lowest active set stake in NAM".to_string(),
+                metric: Family::<NetworkLabels, Gauge::<f64, AtomicU64>>::default(),
+            },
+            namada_network_lowest_active_set_stake_raw: NetworkMetricFloat {
+                name: "namada_network_lowest_active_set_stake_raw".to_string(),
+                help: "Lowest active set stake in raw (micro) units".to_string(),
+                metric: Family::<NetworkLabels, Gauge::<f64, AtomicU64>>::default(),
             },
             namada_network_max_set_size: NetworkMetricInt {
                 name: "namada_network_max_set_size".to_string(),
                 help: "Max set size".to_string(),
                 metric: Family::<NetworkLabels, Gauge>::default(),
             },
-            namada_network_stake_threshold: NetworkMetricInt {
+            namada_network_stake_threshold: NetworkMetricFloat {
                 name: "namada_network_stake_threshold".to_string(),
-                help: "Stake threshold".to_string(),
-                metric: Family::<NetworkLabels, Gauge>::default(),
+                help: "Stake threshold in NAM".to_string(),
+                metric: Family::<NetworkLabels, Gauge::<f64, AtomicU64>>::default(),
+            },
+            namada_network_stake_threshold_raw: NetworkMetricFloat {
+                name: "namada_network_stake_threshold_raw".to_string(),
+                help: "Stake threshold in raw (micro) units".to_string(),
+                metric: Family::<NetworkLabels, Gauge::<f64, AtomicU64>>::default(),
             },
             namada_network_active_set_size: NetworkMetricInt {
                 name: "namada_network_active_set_size".to_string(),
                 help: "Active set size".to_string(),
                 metric: Family::<NetworkLabels, Gauge>::default(),
             },
+            namada_node_validator_voting_power: NodeMetricInt {
+                name: "namada_node_validator_voting_power".to_string(),
+                help: "Voting power of the node's own validator key; 0 value if the node is not a validator".to_string(),
+                metric: Family::<NodeLabels, Gauge>::default(),
+            },
+            namada_node_is_signing_validator: NodeMetricInt {
+                name: "namada_node_is_signing_validator".to_string(),
+                help: "Node signs with the configured validator consensus key; 0 - no, 1 - yes".to_string(),
+                metric: Family::<NodeLabels, Gauge>::default(),
+            },
             namada_validator_missed_blocks: NetworkMetricInt {
                 name: "namada_validator_node_latest_block".to_string(),
                 help: "Latest block from rpc. This metric is deprecated and will be removed in future versions please use namada_node_latest_block".to_string(),
@@ -179,204 +628,1827 @@ impl NamadaMetrics {
                 help: "Latest block from rpc".to_string(),
                 metric: Family::<NodeLabels, Gauge>::default(),
             },
-        };
-        registry.register(
-            metric.namada_validator_uptime_percentage.name.as_str(),
-            metric.namada_validator_uptime_percentage.help.as_str(),
-            metric.namada_validator_uptime_percentage.metric.clone(),
-        );
-        registry.register(
-            metric.namada_validator_state.name.as_str(),
-            metric.namada_validator_state.help.as_str(),
-            metric.namada_validator_state.metric.clone(),
-        );
-        registry.register(
-            metric.namada_validator_active_set_rank.name.as_str(),
-            metric.namada_validator_active_set_rank.help.as_str(),
-            metric.namada_validator_active_set_rank.metric.clone(),
-        );
-        registry.register(
-            metric.namada_missed_blocks.name.as_str(),
-            metric.namada_missed_blocks.help.as_str(),
-            metric.namada_missed_blocks.metric.clone(),
-        );
-        registry.register(
-            metric.namada_total_bonds.name.as_str(),
-            metric.namada_total_bonds.help.as_str(),
-            metric.namada_total_bonds.metric.clone(),
-        );
-        registry.register(
-            metric.validator_commission.name.as_str(),
-            metric.validator_commission.help.as_str(),
-            metric.validator_commission.metric.clone(),
-        );
-        registry.register(
-            metric.namada_network_epoch.name.as_str(),
-            metric.namada_network_epoch.help.as_str(),
-            metric.namada_network_epoch.metric.clone(),
-        );
-        registry.register(
-            metric.namada_node_catch_up.name.as_str(),
-            metric.namada_node_catch_up.help.as_str(),
-            metric.namada_node_catch_up.metric.clone(),
-        );
-        registry.register(
-            metric.namada_network_lowest_active_set_stake.name.as_str(),
-            metric.namada_network_lowest_active_set_stake.help.as_str(),
-            metric.namada_network_lowest_active_set_stake.metric.clone(),
-        );
-        registry.register(
-            metric.namada_network_max_set_size.name.as_str(),
-            metric.namada_network_max_set_size.help.as_str(),
-            metric.namada_network_max_set_size.metric.clone(),
-        );
-        registry.register(
-            metric.namada_network_stake_threshold.name.as_str(),
-            metric.namada_network_stake_threshold.help.as_str(),
-            metric.namada_network_stake_threshold.metric.clone(),
-        );
-        registry.register(
-            metric.namada_network_active_set_size.name.as_str(),
-            metric.namada_network_active_set_size.help.as_str(),
-            metric.namada_network_active_set_size.metric.clone(),
-        );
-        registry.register(
-            metric.namada_node_latest_block.name.as_str(),
-            metric.namada_node_latest_block.help.as_str(),
-            metric.namada_node_latest_block.metric.clone(),
-        );
-        registry.register(
-            metric.namada_validator_missed_blocks.name.as_str(),
-            metric.namada_validator_missed_blocks.help.as_str(),
-            metric.namada_validator_missed_blocks.metric.clone(),
-        );
-        NamadaMetrics {
-            chain_id,
-            registry,
-            tm_address,
-            metrics: metric,
-        }
-    }
-    pub fn set_validator_metrics(&self, validator_data: &ValidatorMetricsData) {
-        self.metrics
-            .namada_validator_uptime_percentage
-            .metric
-            .get_or_create(&ValidatorLabels {
-                chain_id: self.chain_id.clone(),
-                validator_tm_address: self.tm_address.clone(),
-                validator_hash_address: validator_data.validator_address_hash.clone(),
-            })
-            .set(validator_data.namada_validator_uptime_percentage);
-        self.metrics
-            .namada_validator_state
-            .metric
-            .get_or_create(&ValidatorLabels {
-                chain_id: self.chain_id.clone(),
-                validator_tm_address: self.tm_address.clone(),
-                validator_hash_address: validator_data.validator_address_hash.clone(),
-            })
-            .set(validator_data.namada_validator_state);
-        self.metrics
-            .namada_validator_active_set_rank
-            .metric
+            namada_network_pipeline_length: NetworkMetricInt {
+                name: "namada_network_pipeline_length".to_string(),
+                help: "Pipeline length in epochs".to_string(),
+                metric: Family::<NetworkLabels, Gauge>::default(),
+            },
+            namada_network_unbonding_length: NetworkMetricInt {
+                name: "namada_network_unbonding_length".to_string(),
+                help: "Unbonding length in epochs".to_string(),
+                metric: Family::<NetworkLabels, Gauge>::default(),
+            },
+            namada_network_slash_processing_delay: NetworkMetricInt {
+                name: "namada_network_slash_processing_delay".to_string(),
+                help: "Number of epochs after which an enqueued slash is processed".to_string(),
+                metric: Family::<NetworkLabels, Gauge>::default(),
+            },
+            namada_network_cubic_slashing_window_length: NetworkMetricInt {
+                name: "namada_network_cubic_slashing_window_length".to_string(),
+                help: "Cubic slashing window length in epochs".to_string(),
+                metric: Family::<NetworkLabels, Gauge>::default(),
+            },
+            namada_network_duplicate_vote_min_slash_rate: NetworkMetricFloat {
+                name: "namada_network_duplicate_vote_min_slash_rate".to_string(),
+                help: "Minimum slash rate for duplicate vote infractions".to_string(),
+                metric: Family::<NetworkLabels, Gauge::<f64, AtomicU64>>::default(),
+            },
+            namada_network_light_client_attack_min_slash_rate: NetworkMetricFloat {
+                name: "namada_network_light_client_attack_min_slash_rate".to_string(),
+                help: "Minimum slash rate for light client attack infractions".to_string(),
+                metric: Family::<NetworkLabels, Gauge::<f64, AtomicU64>>::default(),
+            },
+            namada_network_max_inflation_rate: NetworkMetricFloat {
+                name: "namada_network_max_inflation_rate".to_string(),
+                help: "Maximum PoS inflation rate".to_string(),
+                metric: Family::<NetworkLabels, Gauge::<f64, AtomicU64>>::default(),
+            },
+            namada_network_target_staked_ratio: NetworkMetricFloat {
+                name: "namada_network_target_staked_ratio".to_string(),
+                help: "Target staked ratio".to_string(),
+                metric: Family::<NetworkLabels, Gauge::<f64, AtomicU64>>::default(),
+            },
+            namada_network_liveness_window: NetworkMetricInt {
+                name: "namada_network_liveness_window".to_string(),
+                help: "Number of blocks in the liveness window".to_string(),
+                metric: Family::<NetworkLabels, Gauge>::default(),
+            },
+            namada_network_liveness_threshold: NetworkMetricFloat {
+                name: "namada_network_liveness_threshold".to_string(),
+                help: "Minimum fraction of signed blocks in the liveness window to avoid jailing".to_string(),
+                metric: Family::<NetworkLabels, Gauge::<f64, AtomicU64>>::default(),
+            },
+            namada_network_epoch_first_block_height: NetworkMetricInt {
+                name: "namada_network_epoch_first_block_height".to_string(),
+                help: "First block height of the current epoch".to_string(),
+                metric: Family::<NetworkLabels, Gauge>::default(),
+            },
+            namada_network_epoch_blocks_elapsed: NetworkMetricInt {
+                name: "namada_network_epoch_blocks_elapsed".to_string(),
+                help: "Number of blocks elapsed since the start of the current epoch".to_string(),
+                metric: Family::<NetworkLabels, Gauge>::default(),
+            },
+            namada_network_epoch_min_blocks: NetworkMetricInt {
+                name: "namada_network_epoch_min_blocks".to_string(),
+                help: "Minimum number of blocks in an epoch".to_string(),
+                metric: Family::<NetworkLabels, Gauge>::default(),
+            },
+            namada_network_epoch_min_duration_seconds: NetworkMetricInt {
+                name: "namada_network_epoch_min_duration_seconds".to_string(),
+                help: "Minimum duration of an epoch in seconds".to_string(),
+                metric: Family::<NetworkLabels, Gauge>::default(),
+            },
+            namada_network_next_epoch_estimated_timestamp: NetworkMetricInt {
+                name: "namada_network_next_epoch_estimated_timestamp".to_string(),
+                help: "Estimated unix timestamp of the start of the next epoch; -1 value if it can not be estimated".to_string(),
+                metric: Family::<NetworkLabels, Gauge>::default(),
+            },
+            namada_network_total_supply: NetworkMetricFloat {
+                name: "namada_network_total_supply".to_string(),
+                help: "Total NAM supply in NAM".to_string(),
+                metric: Family::<NetworkLabels, Gauge::<f64, AtomicU64>>::default(),
+            },
+            namada_network_total_bonded_stake: NetworkMetricFloat {
+                name: "namada_network_total_bonded_stake".to_string(),
+                help: "Total bonded stake in NAM".to_string(),
+                metric: Family::<NetworkLabels, Gauge::<f64, AtomicU64>>::default(),
+            },
+            namada_network_staking_ratio: NetworkMetricFloat {
+                name: "namada_network_staking_ratio".to_string(),
+                help: "Ratio of bonded stake to total NAM supply".to_string(),
+                metric: Family::<NetworkLabels, Gauge::<f64, AtomicU64>>::default(),
+            },
+            namada_network_pos_inflation_rate: NetworkMetricFloat {
+                name: "namada_network_pos_inflation_rate".to_string(),
+                help: "Current annual PoS inflation rate".to_string(),
+                metric: Family::<NetworkLabels, Gauge::<f64, AtomicU64>>::default(),
+            },
+            namada_network_staking_rewards_rate: NetworkMetricFloat {
+                name: "namada_network_staking_rewards_rate".to_string(),
+                help: "Current annual staking rewards rate".to_string(),
+                metric: Family::<NetworkLabels, Gauge::<f64, AtomicU64>>::default(),
+            },
+            namada_network_pgf_inflation_rate: NetworkMetricFloat {
+                name: "namada_network_pgf_inflation_rate".to_string(),
+                help: "Current annual PGF inflation rate".to_string(),
+                metric: Family::<NetworkLabels, Gauge::<f64, AtomicU64>>::default(),
+            },
+            namada_network_total_supply_raw: NetworkMetricFloat {
+                name: "namada_network_total_supply_raw".to_string(),
+                help: "Total NAM supply in raw (micro) units".to_string(),
+                metric: Family::<NetworkLabels, Gauge::<f64, AtomicU64>>::default(),
+            },
+            namada_network_total_bonded_stake_raw: NetworkMetricFloat {
+                name: "namada_network_total_bonded_stake_raw".to_string(),
+                help: "Total bonded stake in raw (micro) units".to_string(),
+                metric: Family::<NetworkLabels, Gauge::<f64, AtomicU64>>::default(),
+            },
+            namada_pgf_steward: PgfStewardMetricInt {
+                name: "namada_pgf_steward".to_string(),
+                help: "PGF steward membership of configured address; 0 - not a steward, 1 - steward".to_string(),
+                metric: Family::<PgfStewardLabels, Gauge>::default(),
+            },
+            namada_pgf_continuous_funding_amount: PgfFundingMetricFloat {
+                name: "namada_pgf_continuous_funding_amount".to_string(),
+                help: "Continuous PGF funding amount paid per epoch in NAM".to_string(),
+                metric: Family::<PgfFundingLabels, Gauge::<f64, AtomicU64>>::default(),
+            },
+            namada_pgf_stewards_count: NetworkMetricInt {
+                name: "namada_pgf_stewards_count".to_string(),
+                help: "Number of PGF stewards".to_string(),
+                metric: Family::<NetworkLabels, Gauge>::default(),
+            },
+            namada_pgf_continuous_fundings_count: NetworkMetricInt {
+                name: "namada_pgf_continuous_fundings_count".to_string(),
+                help: "Number of active continuous PGF fundings".to_string(),
+                metric: Family::<NetworkLabels, Gauge>::default(),
+            },
+            namada_pgf_continuous_funding_total: NetworkMetricFloat {
+                name: "namada_pgf_continuous_funding_total".to_string(),
+                help: "Total continuous PGF funding paid per epoch in NAM".to_string(),
+                metric: Family::<NetworkLabels, Gauge::<f64, AtomicU64>>::default(),
+            },
+            namada_pgf_retro_payment_amount: PgfRetroPaymentMetricFloat {
+                name: "namada_pgf_retro_payment_amount".to_string(),
+                help: "Retroactive PGF payment in NAM paid once in the activation epoch of a passed proposal".to_string(),
+                metric: Family::<PgfRetroPaymentLabels, Gauge::<f64, AtomicU64>>::default(),
+            },
+            namada_pgf_retro_payments_total: NetworkMetricFloat {
+                name: "namada_pgf_retro_payments_total".to_string(),
+                help: "Total retroactive PGF payments in NAM of the scanned recent proposals".to_string(),
+                metric: Family::<NetworkLabels, Gauge::<f64, AtomicU64>>::default(),
+            },
+            namada_account_balance: BalanceMetricFloat {
+                name: "namada_account_balance".to_string(),
+                help: "Account balance in denominated token units".to_string(),
+                metric: Family::<BalanceLabels, Gauge::<f64, AtomicU64>>::default(),
+            },
+            namada_account_balance_raw: BalanceMetricFloat {
+                name: "namada_account_balance_raw".to_string(),
+                help: "Account balance in raw (micro) token units".to_string(),
+                metric: Family::<BalanceLabels, Gauge::<f64, AtomicU64>>::default(),
+            },
+            namada_masp_total_shielded: MaspTokenMetricFloat {
+                name: "namada_masp_total_shielded".to_string(),
+                help: "Total value held in the shielded pool in denominated token units".to_string(),
+                metric: Family::<MaspTokenLabels, Gauge::<f64, AtomicU64>>::default(),
+            },
+            namada_masp_max_reward_rate: MaspTokenMetricFloat {
+                name: "namada_masp_max_reward_rate".to_string(),
+                help: "Maximum MASP reward rate; -1 value if the token is not rewarded".to_string(),
+                metric: Family::<MaspTokenLabels, Gauge::<f64, AtomicU64>>::default(),
+            },
+            namada_masp_kp_gain: MaspTokenMetricFloat {
+                name: "namada_masp_kp_gain".to_string(),
+                help: "MASP rewards controller proportional gain; -1 value if the token is not rewarded".to_string(),
+                metric: Family::<MaspTokenLabels, Gauge::<f64, AtomicU64>>::default(),
+            },
+            namada_masp_kd_gain: MaspTokenMetricFloat {
+                name: "namada_masp_kd_gain".to_string(),
+                help: "MASP rewards controller derivative gain; -1 value if the token is not rewarded".to_string(),
+                metric: Family::<MaspTokenLabels, Gauge::<f64, AtomicU64>>::default(),
+            },
+            namada_masp_locked_amount_target: MaspTokenMetricFloat {
+                name: "namada_masp_locked_amount_target".to_string(),
+                help: "Target shielded amount of the MASP rewards controller in denominated token units; -1 value if the token is not rewarded".to_string(),
+                metric: Family::<MaspTokenLabels, Gauge::<f64, AtomicU64>>::default(),
+            },
+            namada_masp_note_commitment_tree_size: NetworkMetricInt {
+                name: "namada_masp_note_commitment_tree_size".to_string(),
+                help: "Number of notes in the MASP note commitment tree".to_string(),
+                metric: Family::<NetworkLabels, Gauge>::default(),
+            },
+            namada_ibc_client_latest_height: IbcClientMetricInt {
+                name: "namada_ibc_client_latest_height".to_string(),
+                help: "Latest counterparty height tracked by the IBC light client".to_string(),
+                metric: Family::<IbcClientLabels, Gauge>::default(),
+            },
+            namada_ibc_client_trusting_period_remaining_seconds: IbcClientMetricInt {
+                name: "namada_ibc_client_trusting_period_remaining_seconds".to_string(),
+                help: "Seconds until the IBC light client expires; negative value if already expired".to_string(),
+                metric: Family::<IbcClientLabels, Gauge>::default(),
+            },
+            namada_ibc_client_frozen: IbcClientMetricInt {
+                name: "namada_ibc_client_frozen".to_string(),
+                help: "IBC light client frozen status; 0 - active, 1 - frozen".to_string(),
+                metric: Family::<IbcClientLabels, Gauge>::default(),
+            },
+            namada_ibc_channel_state: IbcChannelMetricInt {
+                name: "namada_ibc_channel_state".to_string(),
+                help: "IBC channel state; 0 - uninitialized, 1 - init, 2 - try open, 3 - open, 4 - closed".to_string(),
+                metric: Family::<IbcChannelLabels, Gauge>::default(),
+            },
+            namada_token_total_supply: TokenMetricFloat {
+                name: "namada_token_total_supply".to_string(),
+                help: "Token total supply in denominated token units".to_string(),
+                metric: Family::<TokenLabels, Gauge::<f64, AtomicU64>>::default(),
+            },
+            namada_token_denomination: TokenMetricInt {
+                name: "namada_token_denomination".to_string(),
+                help: "Token denomination (number of decimal places)".to_string(),
+                metric: Family::<TokenLabels, Gauge>::default(),
+            },
+            namada_token_mint_limit: TokenMetricFloat {
+                name: "namada_token_mint_limit".to_string(),
+                help: "IBC mint limit in denominated token units; -1 value if not set for the token".to_string(),
+                metric: Family::<TokenLabels, Gauge::<f64, AtomicU64>>::default(),
+            },
+            namada_token_throughput_limit: TokenMetricFloat {
+                name: "namada_token_throughput_limit".to_string(),
+                help: "IBC throughput limit per epoch in denominated token units; -1 value if not set for the token".to_string(),
+                metric: Family::<TokenLabels, Gauge::<f64, AtomicU64>>::default(),
+            },
+            namada_token_minted_amount: TokenMetricFloat {
+                name: "namada_token_minted_amount".to_string(),
+                help: "Amount minted over IBC in denominated token units; -1 value if not available".to_string(),
+                metric: Family::<TokenLabels, Gauge::<f64, AtomicU64>>::default(),
+            },
+            namada_token_total_supply_raw: TokenMetricFloat {
+                name: "namada_token_total_supply_raw".to_string(),
+                help: "Token total supply in raw (micro) token units".to_string(),
+                metric: Family::<TokenLabels, Gauge::<f64, AtomicU64>>::default(),
+            },
+            namada_node_version_info: VersionMetricInt {
+                name: "namada_node_version_info".to_string(),
+                help: "Node software and protocol versions; value is always 1".to_string(),
+                metric: Family::<VersionLabels, Gauge>::default(),
+            },
+            namada_node_version_mismatch: NodeMetricInt {
+                name: "namada_node_version_mismatch".to_string(),
+                help: "Node Namada version differs from the configured expected version; 0 - match, 1 - mismatch, -1 value if no expected version is configured".to_string(),
+                metric: Family::<NodeLabels, Gauge>::default(),
+            },
+            namada_network_upgrade_height: UpgradeMetricInt {
+                name: "namada_network_upgrade_height".to_string(),
+                help: "Block height of the planned upgrade".to_string(),
+                metric: Family::<UpgradeLabels, Gauge>::default(),
+            },
+            namada_network_upgrade_blocks_remaining: UpgradeMetricInt {
+                name: "namada_network_upgrade_blocks_remaining".to_string(),
+                help: "Blocks remaining until the planned upgrade height".to_string(),
+                metric: Family::<UpgradeLabels, Gauge>::default(),
+            },
+            namada_network_upgrade_estimated_timestamp: UpgradeMetricInt {
+                name: "namada_network_upgrade_estimated_timestamp".to_string(),
+                help: "Estimated unix timestamp of the planned upgrade height; -1 value if it can not be estimated".to_string(),
+                metric: Family::<UpgradeLabels, Gauge>::default(),
+            },
+            namada_network_average_block_time_seconds: NetworkMetricFloat {
+                name: "namada_network_average_block_time_seconds".to_string(),
+                help: "Average block time in seconds observed over recent blocks; -1 value if it can not be computed".to_string(),
+                metric: Family::<NetworkLabels, Gauge::<f64, AtomicU64>>::default(),
+            },
+            namada_block_follower_height: NetworkMetricInt {
+                name: "namada_block_follower_height".to_string(),
+                help: "Height of the last block processed by the block follower".to_string(),
+                metric: Family::<NetworkLabels, Gauge>::default(),
+            },
+            namada_block_last_txs: NetworkMetricInt {
+                name: "namada_block_last_txs".to_string(),
+                help: "Number of transactions in the last processed block".to_string(),
+                metric: Family::<NetworkLabels, Gauge>::default(),
+            },
+            namada_block_last_gas_used: NetworkMetricInt {
+                name: "namada_block_last_gas_used".to_string(),
+                help: "Gas used by the transactions of the last processed block".to_string(),
+                metric: Family::<NetworkLabels, Gauge>::default(),
+            },
+            namada_block_gas_limit: NetworkMetricInt {
+                name: "namada_block_gas_limit".to_string(),
+                help: "Maximum gas allowed per block".to_string(),
+                metric: Family::<NetworkLabels, Gauge>::default(),
+            },
+            namada_block_processed: NetworkMetricCounter {
+                name: "namada_block_processed".to_string(),
+                help: "Number of blocks processed by the block follower".to_string(),
+                metric: Family::<NetworkLabels, Counter>::default(),
+            },
+            namada_block_gas_used: NetworkMetricCounter {
+                name: "namada_block_gas_used".to_string(),
+                help: "Gas used by the transactions of all processed blocks".to_string(),
+                metric: Family::<NetworkLabels, Counter>::default(),
+            },
+            namada_block_txs: TxResultMetricCounter {
+                name: "namada_block_txs".to_string(),
+                help: "Number of transactions in processed blocks by result; success or failure".to_string(),
+                metric: Family::<TxResultLabels, Counter>::default(),
+            },
+            namada_block_inner_txs: TxTypeMetricCounter {
+                name: "namada_block_inner_txs".to_string(),
+                help: "Number of inner transactions in processed blocks by transaction type".to_string(),
+                metric: Family::<TxTypeLabels, Counter>::default(),
+            },
+            namada_validator_tx_events: ValidatorEventMetricCounter {
+                name: "namada_validator_tx_events".to_string(),
+                help: "Number of successful transactions touching the validator by event type".to_string(),
+                metric: Family::<ValidatorEventLabels, Counter>::default(),
+            },
+            namada_validator_tx_event_amount: ValidatorEventMetricFloatCounter {
+                name: "namada_validator_tx_event_amount".to_string(),
+                help: "Amount in NAM of successful bonds, unbonds and redelegations touching the validator by event type".to_string(),
+                metric: Family::<ValidatorEventLabels, Counter::<f64, AtomicU64>>::default(),
+            },
+            namada_validator_voting_power_fraction: ValidatorMetricFloat {
+                name: "namada_validator_voting_power_fraction".to_string(),
+                help: "Validator share of the total bonded stake in the current epoch".to_string(),
+                metric: Family::<ValidatorLabels, Gauge::<f64, AtomicU64>>::default(),
+            },
+            namada_validator_cubic_slash_window_infracting_fraction: ValidatorMetricFloat {
+                name: "namada_validator_cubic_slash_window_infracting_fraction".to_string(),
+                help: "Share of stake of other validators with enqueued slashes within the cubic slashing window around the current epoch".to_string(),
+                metric: Family::<ValidatorLabels, Gauge::<f64, AtomicU64>>::default(),
+            },
+            namada_validator_hypothetical_duplicate_vote_slash_rate: ValidatorMetricFloat {
+                name: "namada_validator_hypothetical_duplicate_vote_slash_rate".to_string(),
+                help: "Slash rate the validator would get for a duplicate vote committed in the current epoch".to_string(),
+                metric: Family::<ValidatorLabels, Gauge::<f64, AtomicU64>>::default(),
+            },
+            namada_validator_hypothetical_light_client_attack_slash_rate: ValidatorMetricFloat {
+                name: "namada_validator_hypothetical_light_client_attack_slash_rate".to_string(),
+                help: "Slash rate the validator would get for a light client attack committed in the current epoch".to_string(),
+                metric: Family::<ValidatorLabels, Gauge::<f64, AtomicU64>>::default(),
+            },
+            namada_network_enqueued_slashes: NetworkMetricInt {
+                name: "namada_network_enqueued_slashes".to_string(),
+                help: "Number of enqueued slashes waiting to be processed".to_string(),
+                metric: Family::<NetworkLabels, Gauge>::default(),
+            },
+            namada_validator_incoming_redelegated_amount: ValidatorMetricFloat {
+                name: "namada_validator_incoming_redelegated_amount".to_string(),
+                help: "Bonds redelegated to the validator from other validators, slashable for infractions of the source validators, in NAM".to_string(),
+                metric: Family::<ValidatorLabels, Gauge::<f64, AtomicU64>>::default(),
+            },
+            namada_validator_outgoing_redelegated_amount: ValidatorMetricFloat {
+                name: "namada_validator_outgoing_redelegated_amount".to_string(),
+                help: "Bonds redelegated from the validator to other validators, slashable for infractions of the validator, in NAM".to_string(),
+                metric: Family::<ValidatorLabels, Gauge::<f64, AtomicU64>>::default(),
+            },
+            namada_exporter_healthcheck_pings: PingResultMetricCounter {
+                name: "namada_exporter_healthcheck_pings".to_string(),
+                help: "Number of health check pings by result after retries; success or failure".to_string(),
+                metric: Family::<PingResultLabels, Counter>::default(),
+            },
+            namada_exporter_healthcheck_last_success_timestamp: NetworkMetricInt {
+                name: "namada_exporter_healthcheck_last_success_timestamp".to_string(),
+                help: "Unix timestamp of the last successful health check ping; -1 if no ping succeeded yet".to_string(),
+                metric: Family::<NetworkLabels, Gauge>::default(),
+            },
+        };
+        registry.register(
+            metric.namada_validator_uptime_percentage.name.as_str(),
+            metric.namada_validator_uptime_percentage.help.as_str(),
+            metric.namada_validator_uptime_percentage.metric.clone(),
+        );
+        registry.register(
+            metric.namada_validator_state.name.as_str(),
+            metric.namada_validator_state.help.as_str(),
+            metric.namada_validator_state.metric.clone(),
+        );
+        registry.register(
+            metric.namada_validator_active_set_rank.name.as_str(),
+            metric.namada_validator_active_set_rank.help.as_str(),
+            metric.namada_validator_active_set_rank.metric.clone(),
+        );
+        registry.register(
+            metric.namada_missed_blocks.name.as_str(),
+            metric.namada_missed_blocks.help.as_str(),
+            metric.namada_missed_blocks.metric.clone(),
+        );
+        registry.register(
+            metric.namada_total_bonds.name.as_str(),
+            metric.namada_total_bonds.help.as_str(),
+            metric.namada_total_bonds.metric.clone(),
+        );
+        registry.register(
+            metric.namada_validator_consensus_key_mismatch.name.as_str(),
+            metric.namada_validator_consensus_key_mismatch.help.as_str(),
+            metric
+                .namada_validator_consensus_key_mismatch
+                .metric
+                .clone(),
+        );
+        registry.register(
+            metric
+                .namada_validator_pending_consensus_key_epoch
+                .name
+                .as_str(),
+            metric
+                .namada_validator_pending_consensus_key_epoch
+                .help
+                .as_str(),
+            metric
+                .namada_validator_pending_consensus_key_epoch
+                .metric
+                .clone(),
+        );
+        registry.register(
+            metric.validator_commission.name.as_str(),
+            metric.validator_commission.help.as_str(),
+            metric.validator_commission.metric.clone(),
+        );
+        registry.register(
+            metric.namada_network_epoch.name.as_str(),
+            metric.namada_network_epoch.help.as_str(),
+            metric.namada_network_epoch.metric.clone(),
+        );
+        registry.register(
+            metric.namada_node_catch_up.name.as_str(),
+            metric.namada_node_catch_up.help.as_str(),
+            metric.namada_node_catch_up.metric.clone(),
+        );
+        registry.register(
+            metric.namada_network_lowest_active_set_stake.name.as_str(),
+            metric.namada_network_lowest_active_set_stake.help.as_str(),
+            metric.namada_network_lowest_active_set_stake.metric.clone(),
+        );
+        registry.register(
+            metric.namada_network_max_set_size.name.as_str(),
+            metric.namada_network_max_set_size.help.as_str(),
+            metric.namada_network_max_set_size.metric.clone(),
+        );
+        registry.register(
+            metric.namada_network_stake_threshold.name.as_str(),
+            metric.namada_network_stake_threshold.help.as_str(),
+            metric.namada_network_stake_threshold.metric.clone(),
+        );
+        registry.register(
+            metric.namada_network_active_set_size.name.as_str(),
+            metric.namada_network_active_set_size.help.as_str(),
+            metric.namada_network_active_set_size.metric.clone(),
+        );
+        registry.register(
+            metric.namada_node_latest_block.name.as_str(),
+            metric.namada_node_latest_block.help.as_str(),
+            metric.namada_node_latest_block.metric.clone(),
+        );
+        registry.register(
+            metric.namada_node_validator_voting_power.name.as_str(),
+            metric.namada_node_validator_voting_power.help.as_str(),
+            metric.namada_node_validator_voting_power.metric.clone(),
+        );
+        registry.register(
+            metric.namada_node_is_signing_validator.name.as_str(),
+            metric.namada_node_is_signing_validator.help.as_str(),
+            metric.namada_node_is_signing_validator.metric.clone(),
+        );
+        registry.register(
+            metric.namada_validator_missed_blocks.name.as_str(),
+            metric.namada_validator_missed_blocks.help.as_str(),
+            metric.namada_validator_missed_blocks.metric.clone(),
+        );
+        registry.register(
+            metric.namada_network_pipeline_length.name.as_str(),
+            metric.namada_network_pipeline_length.help.as_str(),
+            metric.namada_network_pipeline_length.metric.clone(),
+        );
+        registry.register(
+            metric.namada_network_unbonding_length.name.as_str(),
+            metric.namada_network_unbonding_length.help.as_str(),
+            metric.namada_network_unbonding_length.metric.clone(),
+        );
+        registry.register(
+            metric.namada_network_slash_processing_delay.name.as_str(),
+            metric.namada_network_slash_processing_delay.help.as_str(),
+            metric.namada_network_slash_processing_delay.metric.clone(),
+        );
+        registry.register(
+            metric
+                .namada_network_cubic_slashing_window_length
+                .name
+                .as_str(),
+            metric
+                .namada_network_cubic_slashing_window_length
+                .help
+                .as_str(),
+            metric
+                .namada_network_cubic_slashing_window_length
+                .metric
+                .clone(),
+        );
+        registry.register(
+            metric
+                .namada_network_duplicate_vote_min_slash_rate
+                .name
+                .as_str(),
+            metric
+                .namada_network_duplicate_vote_min_slash_rate
+                .help
+                .as_str(),
+            metric
+                .namada_network_duplicate_vote_min_slash_rate
+                .metric
+                .clone(),
+        );
+        registry.register(
+            metric
+                .namada_network_light_client_attack_min_slash_rate
+                .name
+                .as_str(),
+            metric
+                .namada_network_light_client_attack_min_slash_rate
+                .help
+                .as_str(),
+            metric
+                .namada_network_light_client_attack_min_slash_rate
+                .metric
+                .clone(),
+        );
+        registry.register(
+            metric.namada_network_max_inflation_rate.name.as_str(),
+            metric.namada_network_max_inflation_rate.help.as_str(),
+            metric.namada_network_max_inflation_rate.metric.clone(),
+        );
+        registry.register(
+            metric.namada_network_target_staked_ratio.name.as_str(),
+            metric.namada_network_target_staked_ratio.help.as_str(),
+            metric.namada_network_target_staked_ratio.metric.clone(),
+        );
+        registry.register(
+            metric.namada_network_liveness_window.name.as_str(),
+            metric.namada_network_liveness_window.help.as_str(),
+            metric.namada_network_liveness_window.metric.clone(),
+        );
+        registry.register(
+            metric.namada_network_liveness_threshold.name.as_str(),
+            metric.namada_network_liveness_threshold.help.as_str(),
+            metric.namada_network_liveness_threshold.metric.clone(),
+        );
+        registry.register(
+            metric.namada_network_epoch_first_block_height.name.as_str(),
+            metric.namada_network_epoch_first_block_height.help.as_str(),
+            metric
+                .namada_network_epoch_first_block_height
+                .metric
+                .clone(),
+        );
+        registry.register(
+            metric.namada_network_epoch_blocks_elapsed.name.as_str(),
+            metric.namada_network_epoch_blocks_elapsed.help.as_str(),
+            metric.namada_network_epoch_blocks_elapsed.metric.clone(),
+        );
+        registry.register(
+            metric.namada_network_epoch_min_blocks.name.as_str(),
+            metric.namada_network_epoch_min_blocks.help.as_str(),
+            metric.namada_network_epoch_min_blocks.metric.clone(),
+        );
+        registry.register(
+            metric
+                .namada_network_epoch_min_duration_seconds
+                .name
+                .as_str(),
+            metric
+                .namada_network_epoch_min_duration_seconds
+                .help
+                .as_str(),
+            metric
+                .namada_network_epoch_min_duration_seconds
+                .metric
+                .clone(),
+        );
+        registry.register(
+            metric
+                .namada_network_next_epoch_estimated_timestamp
+                .name
+                .as_str(),
+            metric
+                .namada_network_next_epoch_estimated_timestamp
+                .help
+                .as_str(),
+            metric
+                .namada_network_next_epoch_estimated_timestamp
+                .metric
+                .clone(),
+        );
+        registry.register(
+            metric.namada_network_total_supply.name.as_str(),
+            metric.namada_network_total_supply.help.as_str(),
+            metric.namada_network_total_supply.metric.clone(),
+        );
+        registry.register(
+            metric.namada_network_total_bonded_stake.name.as_str(),
+            metric.namada_network_total_bonded_stake.help.as_str(),
+            metric.namada_network_total_bonded_stake.metric.clone(),
+        );
+        registry.register(
+            metric.namada_network_staking_ratio.name.as_str(),
+            metric.namada_network_staking_ratio.help.as_str(),
+            metric.namada_network_staking_ratio.metric.clone(),
+        );
+        registry.register(
+            metric.namada_network_pos_inflation_rate.name.as_str(),
+            metric.namada_network_pos_inflation_rate.help.as_str(),
+            metric.namada_network_pos_inflation_rate.metric.clone(),
+        );
+        registry.register(
+            metric.namada_network_staking_rewards_rate.name.as_str(),
+            metric.namada_network_staking_rewards_rate.help.as_str(),
+            metric.namada_network_staking_rewards_rate.metric.clone(),
+        );
+        registry.register(
+            metric.namada_network_pgf_inflation_rate.name.as_str(),
+            metric.namada_network_pgf_inflation_rate.help.as_str(),
+            metric.namada_network_pgf_inflation_rate.metric.clone(),
+        );
+        registry.register(
+            metric.namada_pgf_steward.name.as_str(),
+            metric.namada_pgf_steward.help.as_str(),
+            metric.namada_pgf_steward.metric.clone(),
+        );
+        registry.register(
+            metric.namada_pgf_continuous_funding_amount.name.as_str(),
+            metric.namada_pgf_continuous_funding_amount.help.as_str(),
+            metric.namada_pgf_continuous_funding_amount.metric.clone(),
+        );
+        registry.register(
+            metric.namada_pgf_stewards_count.name.as_str(),
+            metric.namada_pgf_stewards_count.help.as_str(),
+            metric.namada_pgf_stewards_count.metric.clone(),
+        );
+        registry.register(
+            metric.namada_pgf_continuous_fundings_count.name.as_str(),
+            metric.namada_pgf_continuous_fundings_count.help.as_str(),
+            metric.namada_pgf_continuous_fundings_count.metric.clone(),
+        );
+        registry.register(
+            metric.namada_pgf_continuous_funding_total.name.as_str(),
+            metric.namada_pgf_continuous_funding_total.help.as_str(),
+            metric.namada_pgf_continuous_funding_total.metric.clone(),
+        );
+        registry.register(
+            metric.namada_pgf_retro_payment_amount.name.as_str(),
+            metric.namada_pgf_retro_payment_amount.help.as_str(),
+            metric.namada_pgf_retro_payment_amount.metric.clone(),
+        );
+        registry.register(
+            metric.namada_pgf_retro_payments_total.name.as_str(),
+            metric.namada_pgf_retro_payments_total.help.as_str(),
+            metric.namada_pgf_retro_payments_total.metric.clone(),
+        );
+        registry.register(
+            metric.namada_account_balance.name.as_str(),
+            metric.namada_account_balance.help.as_str(),
+            metric.namada_account_balance.metric.clone(),
+        );
+        registry.register(
+            metric.namada_masp_total_shielded.name.as_str(),
+            metric.namada_masp_total_shielded.help.as_str(),
+            metric.namada_masp_total_shielded.metric.clone(),
+        );
+        registry.register(
+            metric.namada_masp_max_reward_rate.name.as_str(),
+            metric.namada_masp_max_reward_rate.help.as_str(),
+            metric.namada_masp_max_reward_rate.metric.clone(),
+        );
+        registry.register(
+            metric.namada_masp_kp_gain.name.as_str(),
+            metric.namada_masp_kp_gain.help.as_str(),
+            metric.namada_masp_kp_gain.metric.clone(),
+        );
+        registry.register(
+            metric.namada_masp_kd_gain.name.as_str(),
+            metric.namada_masp_kd_gain.help.as_str(),
+            metric.namada_masp_kd_gain.metric.clone(),
+        );
+        registry.register(
+            metric.namada_masp_locked_amount_target.name.as_str(),
+            metric.namada_masp_locked_amount_target.help.as_str(),
+            metric.namada_masp_locked_amount_target.metric.clone(),
+        );
+        registry.register(
+            metric.namada_masp_note_commitment_tree_size.name.as_str(),
+            metric.namada_masp_note_commitment_tree_size.help.as_str(),
+            metric.namada_masp_note_commitment_tree_size.metric.clone(),
+        );
+        registry.register(
+            metric.namada_ibc_client_latest_height.name.as_str(),
+            metric.namada_ibc_client_latest_height.help.as_str(),
+            metric.namada_ibc_client_latest_height.metric.clone(),
+        );
+        registry.register(
+            metric
+                .namada_ibc_client_trusting_period_remaining_seconds
+                .name
+                .as_str(),
+            metric
+                .namada_ibc_client_trusting_period_remaining_seconds
+                .help
+                .as_str(),
+            metric
+                .namada_ibc_client_trusting_period_remaining_seconds
+                .metric
+                .clone(),
+        );
+        registry.register(
+            metric.namada_ibc_client_frozen.name.as_str(),
+            metric.namada_ibc_client_frozen.help.as_str(),
+            metric.namada_ibc_client_frozen.metric.clone(),
+        );
+        registry.register(
+            metric.namada_ibc_channel_state.name.as_str(),
+            metric.namada_ibc_channel_state.help.as_str(),
+            metric.namada_ibc_channel_state.metric.clone(),
+        );
+        registry.register(
+            metric.namada_token_total_supply.name.as_str(),
+            metric.namada_token_total_supply.help.as_str(),
+            metric.namada_token_total_supply.metric.clone(),
+        );
+        registry.register(
+            metric.namada_token_denomination.name.as_str(),
+            metric.namada_token_denomination.help.as_str(),
+            metric.namada_token_denomination.metric.clone(),
+        );
+        registry.register(
+            metric.namada_token_mint_limit.name.as_str(),
+            metric.namada_token_mint_limit.help.as_str(),
+            metric.namada_token_mint_limit.metric.clone(),
+        );
+        registry.register(
+            metric.namada_token_throughput_limit.name.as_str(),
+            metric.namada_token_throughput_limit.help.as_str(),
+            metric.namada_token_throughput_limit.metric.clone(),
+        );
+        registry.register(
+            metric.namada_token_minted_amount.name.as_str(),
+            metric.namada_token_minted_amount.help.as_str(),
+            metric.namada_token_minted_amount.metric.clone(),
+        );
+        registry.register(
+            metric.namada_node_version_info.name.as_str(),
+            metric.namada_node_version_info.help.as_str(),
+            metric.namada_node_version_info.metric.clone(),
+        );
+        registry.register(
+            metric.namada_node_version_mismatch.name.as_str(),
+            metric.namada_node_version_mismatch.help.as_str(),
+            metric.namada_node_version_mismatch.metric.clone(),
+        );
+        registry.register(
+            metric.namada_network_upgrade_height.name.as_str(),
+            metric.namada_network_upgrade_height.help.as_str(),
+            metric.namada_network_upgrade_height.metric.clone(),
+        );
+        registry.register(
+            metric.namada_network_upgrade_blocks_remaining.name.as_str(),
+            metric.namada_network_upgrade_blocks_remaining.help.as_str(),
+            metric
+                .namada_network_upgrade_blocks_remaining
+                .metric
+                .clone(),
+        );
+        registry.register(
+            metric
+                .namada_network_upgrade_estimated_timestamp
+                .name
+                .as_str(),
+            metric
+                .namada_network_upgrade_estimated_timestamp
+                .help
+                .as_str(),
+            metric
+                .namada_network_upgrade_estimated_timestamp
+                .metric
+                .clone(),
+        );
+        registry.register(
+            metric
+                .namada_network_average_block_time_seconds
+                .name
+                .as_str(),
+            metric
+                .namada_network_average_block_time_seconds
+                .help
+                .as_str(),
+            metric
+                .namada_network_average_block_time_seconds
+                .metric
+                .clone(),
+        );
+        registry.register(
+            metric.namada_block_follower_height.name.as_str(),
+            metric.namada_block_follower_height.help.as_str(),
+            metric.namada_block_follower_height.metric.clone(),
+        );
+        registry.register(
+            metric.namada_block_last_txs.name.as_str(),
+            metric.namada_block_last_txs.help.as_str(),
+            metric.namada_block_last_txs.metric.clone(),
+        );
+        registry.register(
+            metric.namada_block_last_gas_used.name.as_str(),
+            metric.namada_block_last_gas_used.help.as_str(),
+            metric.namada_block_last_gas_used.metric.clone(),
+        );
+        registry.register(
+            metric.namada_block_gas_limit.name.as_str(),
+            metric.namada_block_gas_limit.help.as_str(),
+            metric.namada_block_gas_limit.metric.clone(),
+        );
+        registry.register(
+            metric.namada_block_processed.name.as_str(),
+            metric.namada_block_processed.help.as_str(),
+            metric.namada_block_processed.metric.clone(),
+        );
+        registry.register(
+            metric.namada_block_gas_used.name.as_str(),
+            metric.namada_block_gas_used.help.as_str(),
+            metric.namada_block_gas_used.metric.clone(),
+        );
+        registry.register(
+            metric.namada_block_txs.name.as_str(),
+            metric.namada_block_txs.help.as_str(),
+            metric.namada_block_txs.metric.clone(),
+        );
+        registry.register(
+            metric.namada_block_inner_txs.name.as_str(),
+            metric.namada_block_inner_txs.help.as_str(),
+            metric.namada_block_inner_txs.metric.clone(),
+        );
+        registry.register(
+            metric.namada_validator_tx_events.name.as_str(),
+            metric.namada_validator_tx_events.help.as_str(),
+            metric.namada_validator_tx_events.metric.clone(),
+        );
+        registry.register(
+            metric.namada_validator_tx_event_amount.name.as_str(),
+            metric.namada_validator_tx_event_amount.help.as_str(),
+            metric.namada_validator_tx_event_amount.metric.clone(),
+        );
+        registry.register(
+            metric.namada_validator_voting_power_fraction.name.as_str(),
+            metric.namada_validator_voting_power_fraction.help.as_str(),
+            metric.namada_validator_voting_power_fraction.metric.clone(),
+        );
+        registry.register(
+            metric
+                .namada_validator_cubic_slash_window_infracting_fraction
+                .name
+                .as_str(),
+            metric
+                .namada_validator_cubic_slash_window_infracting_fraction
+                .help
+                .as_str(),
+            metric
+                .namada_validator_cubic_slash_window_infracting_fraction
+                .metric
+                .clone(),
+        );
+        registry.register(
+            metric
+                .namada_validator_hypothetical_duplicate_vote_slash_rate
+                .name
+                .as_str(),
+            metric
+                .namada_validator_hypothetical_duplicate_vote_slash_rate
+                .help
+                .as_str(),
+            metric
+                .namada_validator_hypothetical_duplicate_vote_slash_rate
+                .metric
+                .clone(),
+        );
+        registry.register(
+            metric
+                .namada_validator_hypothetical_light_client_attack_slash_rate
+                .name
+                .as_str(),
+            metric
+                .namada_validator_hypothetical_light_client_attack_slash_rate
+                .help
+                .as_str(),
+            metric
+                .namada_validator_hypothetical_light_client_attack_slash_rate
+                .metric
+                .clone(),
+        );
+        registry.register(
+            metric.namada_network_enqueued_slashes.name.as_str(),
+            metric.namada_network_enqueued_slashes.help.as_str(),
+            metric.namada_network_enqueued_slashes.metric.clone(),
+        );
+        registry.register(
+            metric
+                .namada_validator_incoming_redelegated_amount
+                .name
+                .as_str(),
+            metric
+                .namada_validator_incoming_redelegated_amount
+                .help
+                .as_str(),
+            metric
+                .namada_validator_incoming_redelegated_amount
+                .metric
+                .clone(),
+        );
+        registry.register(
+            metric
+                .namada_validator_outgoing_redelegated_amount
+                .name
+                .as_str(),
+            metric
+                .namada_validator_outgoing_redelegated_amount
+                .help
+                .as_str(),
+            metric
+                .namada_validator_outgoing_redelegated_amount
+                .metric
+                .clone(),
+        );
+        registry.register(
+            metric.namada_exporter_healthcheck_pings.name.as_str(),
+            metric.namada_exporter_healthcheck_pings.help.as_str(),
+            metric.namada_exporter_healthcheck_pings.metric.clone(),
+        );
+        registry.register(
+            metric
+                .namada_exporter_healthcheck_last_success_timestamp
+                .name
+                .as_str(),
+            metric
+                .namada_exporter_healthcheck_last_success_timestamp
+                .help
+                .as_str(),
+            metric
+                .namada_exporter_healthcheck_last_success_timestamp
+                .metric
+                .clone(),
+        );
+        if export_raw_amounts {
+            registry.register(
+                metric.namada_token_total_supply_raw.name.as_str(),
+                metric.namada_token_total_supply_raw.help.as_str(),
+                metric.namada_token_total_supply_raw.metric.clone(),
+            );
+            registry.register(
+                metric.namada_account_balance_raw.name.as_str(),
+                metric.namada_account_balance_raw.help.as_str(),
+                metric.namada_account_balance_raw.metric.clone(),
+            );
+            registry.register(
+                metric.namada_network_total_supply_raw.name.as_str(),
+                metric.namada_network_total_supply_raw.help.as_str(),
+                metric.namada_network_total_supply_raw.metric.clone(),
+            );
+            registry.register(
+                metric.namada_network_total_bonded_stake_raw.name.as_str(),
+                metric.namada_network_total_bonded_stake_raw.help.as_str(),
+                metric.namada_network_total_bonded_stake_raw.metric.clone(),
+            );
+            registry.register(
+                metric.namada_total_bonds_raw.name.as_str(),
+                metric.namada_total_bonds_raw.help.as_str(),
+                metric.namada_total_bonds_raw.metric.clone(),
+            );
+            registry.register(
+                metric
+                    .namada_network_lowest_active_set_stake_raw
+                    .name
+                    .as_str(),
+                metric
+                    .namada_network_lowest_active_set_stake_raw
+                    .help
+                    .as_str(),
+                metric
+                    .namada_network_lowest_active_set_stake_raw
+                    .metric
+                    .clone(),
+            );
+            registry.register(
+                metric.namada_network_stake_threshold_raw.name.as_str(),
+                metric.namada_network_stake_threshold_raw.help.as_str(),
+                metric.namada_network_stake_threshold_raw.metric.clone(),
+            );
+        }
+        NamadaMetrics {
+            chain_id,
+            registry,
+            tm_address,
+            metrics: metric,
+        }
+    }
+    pub fn set_validator_metrics(&self, validator_data: &ValidatorMetricsData) {
+        let validator_hash_address = validator_data
+            .validator_address_hash
+            .clone()
+            .unwrap_or_default();
+        self.metrics
+            .namada_validator_uptime_percentage
+            .metric
             .get_or_create(&ValidatorLabels {
                 chain_id: self.chain_id.clone(),
-                validator_tm_address: self.tm_address.clone(),
-                validator_hash_address: validator_data.validator_address_hash.clone(),
+                validator_tm_address: self.tm_address.clone(),
+                validator_hash_address: validator_hash_address.clone(),
+            })
+            .set(
+                validator_data
+                    .namada_validator_uptime_percentage
+                    .unwrap_or(-1),
+            );
+        self.metrics
+            .namada_validator_state
+            .metric
+            .get_or_create(&ValidatorLabels {
+                chain_id: self.chain_id.clone(),
+                validator_tm_address: self.tm_address.clone(),
+                validator_hash_address: validator_hash_address.clone(),
+            })
+            .set(validator_data.namada_validator_state.unwrap_or(-1));
+        self.metrics
+            .namada_validator_active_set_rank
+            .metric
+            .get_or_create(&ValidatorLabels {
+                chain_id: self.chain_id.clone(),
+                validator_tm_address: self.tm_address.clone(),
+                validator_hash_address: validator_hash_address.clone(),
+            })
+            .set(
+                validator_data
+                    .namada_validator_active_set_rank
+                    .unwrap_or(-1),
+            );
+        self.metrics
+            .namada_missed_blocks
+            .metric
+            .get_or_create(&ValidatorLabels {
+                chain_id: self.chain_id.clone(),
+                validator_tm_address: self.tm_address.clone(),
+                validator_hash_address: validator_hash_address.clone(),
+            })
+            .set(validator_data.namada_missed_blocks.unwrap_or(-1));
+        self.metrics
+            .namada_total_bonds
+            .metric
+            .get_or_create(&ValidatorLabels {
+                chain_id: self.chain_id.clone(),
+                validator_tm_address: self.tm_address.clone(),
+                validator_hash_address: validator_hash_address.clone(),
+            })
+            .set(validator_data.namada_total_bonds.unwrap_or(-1.0));
+        self.metrics
+            .namada_total_bonds_raw
+            .metric
+            .get_or_create(&ValidatorLabels {
+                chain_id: self.chain_id.clone(),
+                validator_tm_address: self.tm_address.clone(),
+                validator_hash_address: validator_hash_address.clone(),
+            })
+            .set(validator_data.namada_total_bonds_raw.unwrap_or(-1.0));
+        self.metrics
+            .namada_validator_consensus_key_mismatch
+            .metric
+            .get_or_create(&ValidatorLabels {
+                chain_id: self.chain_id.clone(),
+                validator_tm_address: self.tm_address.clone(),
+                validator_hash_address: validator_hash_address.clone(),
+            })
+            .set(
+                validator_data
+                    .namada_validator_consensus_key_mismatch
+                    .map(i64::from)
+                    .unwrap_or(-1),
+            );
+        self.metrics
+            .namada_validator_pending_consensus_key_epoch
+            .metric
+            .get_or_create(&ValidatorLabels {
+                chain_id: self.chain_id.clone(),
+                validator_tm_address: self.tm_address.clone(),
+                validator_hash_address: validator_hash_address.clone(),
+            })
+            .set(
+                validator_data
+                    .namada_validator_pending_consensus_key_epoch
+                    .unwrap_or(-1),
+            );
+        let rounded: f64 = match validator_data.validator_commission {
+            Some(commission) => format!("{:.2}", commission)
+                .parse()
+                .expect("Failed to parse commission"),
+            None => -1.0,
+        };
+        self.metrics
+            .validator_commission
+            .metric
+            .get_or_create(&ValidatorLabels {
+                chain_id: self.chain_id.clone(),
+                validator_tm_address: self.tm_address.clone(),
+                validator_hash_address: validator_hash_address.clone(),
+            })
+            .set(rounded);
+    }
+    pub fn set_network_metrics(&self, network_data: &NetworkMetricsData) {
+        self.metrics
+            .namada_network_epoch
+            .metric
+            .get_or_create(&NetworkLabels {
+                chain_id: self.chain_id.clone(),
+            })
+            .set(network_data.namada_network_epoch);
+        self.metrics
+            .namada_node_catch_up
+            .metric
+            .get_or_create(&NetworkLabels {
+                chain_id: self.chain_id.clone(),
+            })
+            .set(network_data.namada_node_catch_up);
+        self.metrics
+            .namada_network_lowest_active_set_stake
+            .metric
+            .get_or_create(&NetworkLabels {
+                chain_id: self.chain_id.clone(),
+            })
+            .set(
+                network_data
+                    .namada_network_lowest_active_set_stake
+                    .unwrap_or(-1.0),
+            );
+        self.metrics
+            .namada_network_lowest_active_set_stake_raw
+            .metric
+            .get_or_create(&NetworkLabels {
+                chain_id: self.chain_id.clone(),
+            })
+            .set(
+                network_data
+                    .namada_network_lowest_active_set_stake_raw
+                    .unwrap_or(-1.0),
+            );
+        self.metrics
+            .namada_network_max_set_size
+            .metric
+            .get_or_create(&NetworkLabels {
+                chain_id: self.chain_id.clone(),
+            })
+            .set(network_data.namada_network_max_set_size);
+        self.metrics
+            .namada_network_stake_threshold
+            .metric
+            .get_or_create(&NetworkLabels {
+                chain_id: self.chain_id.clone(),
+            })
+            .set(network_data.namada_network_stake_threshold);
+        self.metrics
+            .namada_network_stake_threshold_raw
+            .metric
+            .get_or_create(&NetworkLabels {
+                chain_id: self.chain_id.clone(),
+            })
+            .set(network_data.namada_network_stake_threshold_raw);
+        self.metrics
+            .namada_network_active_set_size
+            .metric
+            .get_or_create(&NetworkLabels {
+                chain_id: self.chain_id.clone(),
+            })
+            .set(network_data.namada_network_active_set_size);
+    }
+    pub fn set_node_metrics(&self, node_data: &NodeMetricsData) {
+        self.metrics
+            .namada_node_latest_block
+            .metric
+            .get_or_create(&NodeLabels {
+                chain_id: self.chain_id.clone(),
+                node_id: node_data.node_id.clone(),
+                moniker: node_data.moniker.clone(),
+            })
+            .set(node_data.namada_node_latest_block);
+        self.metrics
+            .namada_node_validator_voting_power
+            .metric
+            .get_or_create(&NodeLabels {
+                chain_id: self.chain_id.clone(),
+                node_id: node_data.node_id.clone(),
+                moniker: node_data.moniker.clone(),
+            })
+            .set(node_data.namada_node_validator_voting_power);
+        self.metrics
+            .namada_node_is_signing_validator
+            .metric
+            .get_or_create(&NodeLabels {
+                chain_id: self.chain_id.clone(),
+                node_id: node_data.node_id.clone(),
+                moniker: node_data.moniker.clone(),
+            })
+            .set(node_data.namada_node_is_signing_validator);
+        self.metrics
+            .namada_validator_missed_blocks
+            .metric
+            .get_or_create(&NetworkLabels {
+                chain_id: self.chain_id.clone(),
+            })
+            .set(node_data.namada_node_latest_block);
+    }
+    pub fn set_pos_params_metrics(&self, pos_params_data: &PosParamsMetricsData) {
+        self.metrics
+            .namada_network_pipeline_length
+            .metric
+            .get_or_create(&NetworkLabels {
+                chain_id: self.chain_id.clone(),
             })
-            .set(validator_data.namada_validator_active_set_rank);
+            .set(pos_params_data.namada_network_pipeline_length);
         self.metrics
-            .namada_missed_blocks
+            .namada_network_unbonding_length
             .metric
-            .get_or_create(&ValidatorLabels {
+            .get_or_create(&NetworkLabels {
                 chain_id: self.chain_id.clone(),
-                validator_tm_address: self.tm_address.clone(),
-                validator_hash_address: validator_data.validator_address_hash.clone(),
             })
-            .set(validator_data.namada_missed_blocks);
+            .set(pos_params_data.namada_network_unbonding_length);
         self.metrics
-            .namada_total_bonds
+            .namada_network_slash_processing_delay
             .metric
-            .get_or_create(&ValidatorLabels {
+            .get_or_create(&NetworkLabels {
                 chain_id: self.chain_id.clone(),
-                validator_tm_address: self.tm_address.clone(),
-                validator_hash_address: validator_data.validator_address_hash.clone(),
             })
-            .set(validator_data.namada_total_bonds);
-        let rounded: f64 = format!("{:.2}", validator_data.validator_commission as f64)
-            .parse()
-            .expect("Failed to parse commission");
+            .set(pos_params_data.namada_network_slash_processing_delay);
         self.metrics
-            .validator_commission
+            .namada_network_cubic_slashing_window_length
             .metric
-            .get_or_create(&ValidatorLabels {
+            .get_or_create(&NetworkLabels {
                 chain_id: self.chain_id.clone(),
-                validator_tm_address: self.tm_address.clone(),
-                validator_hash_address: validator_data.validator_address_hash.clone(),
             })
-            .set(rounded);
+            .set(pos_params_data.namada_network_cubic_slashing_window_length);
+        self.metrics
+            .namada_network_duplicate_vote_min_slash_rate
+            .metric
+            .get_or_create(&NetworkLabels {
+                chain_id: self.chain_id.clone(),
+            })
+            .set(pos_params_data.namada_network_duplicate_vote_min_slash_rate);
+        self.metrics
+            .namada_network_light_client_attack_min_slash_rate
+            .metric
+            .get_or_create(&NetworkLabels {
+                chain_id: self.chain_id.clone(),
+            })
+            .set(pos_params_data.namada_network_light_client_attack_min_slash_rate);
+        self.metrics
+            .namada_network_max_inflation_rate
+            .metric
+            .get_or_create(&NetworkLabels {
+                chain_id: self.chain_id.clone(),
+            })
+            .set(pos_params_data.namada_network_max_inflation_rate);
+        self.metrics
+            .namada_network_target_staked_ratio
+            .metric
+            .get_or_create(&NetworkLabels {
+                chain_id: self.chain_id.clone(),
+            })
+            .set(pos_params_data.namada_network_target_staked_ratio);
+        self.metrics
+            .namada_network_liveness_window
+            .metric
+            .get_or_create(&NetworkLabels {
+                chain_id: self.chain_id.clone(),
+            })
+            .set(pos_params_data.namada_network_liveness_window);
+        self.metrics
+            .namada_network_liveness_threshold
+            .metric
+            .get_or_create(&NetworkLabels {
+                chain_id: self.chain_id.clone(),
+            })
+            .set(pos_params_data.namada_network_liveness_threshold);
     }
-    pub fn set_network_metrics(&self, network_data: &NetworkMetricsData) {
+    pub fn set_epoch_metrics(&self, epoch_data: &EpochMetricsData) {
         self.metrics
-            .namada_network_epoch
+            .namada_network_epoch_first_block_height
             .metric
             .get_or_create(&NetworkLabels {
                 chain_id: self.chain_id.clone(),
             })
-            .set(network_data.namada_network_epoch);
+            .set(epoch_data.namada_network_epoch_first_block_height);
         self.metrics
-            .namada_node_catch_up
+            .namada_network_epoch_blocks_elapsed
             .metric
             .get_or_create(&NetworkLabels {
                 chain_id: self.chain_id.clone(),
             })
-            .set(network_data.namada_node_catch_up);
+            .set(epoch_data.namada_network_epoch_blocks_elapsed);
         self.metrics
-            .namada_network_lowest_active_set_stake
+            .namada_network_epoch_min_blocks
             .metric
             .get_or_create(&NetworkLabels {
                 chain_id: self.chain_id.clone(),
             })
-            .set(network_data.namada_network_lowest_active_set_stake);
+            .set(epoch_data.namada_network_epoch_min_blocks);
         self.metrics
-            .namada_network_max_set_size
+            .namada_network_epoch_min_duration_seconds
             .metric
             .get_or_create(&NetworkLabels {
                 chain_id: self.chain_id.clone(),
             })
-            .set(network_data.namada_network_max_set_size);
+            .set(epoch_data.namada_network_epoch_min_duration_seconds);
         self.metrics
-            .namada_network_stake_threshold
+            .namada_network_next_epoch_estimated_timestamp
             .metric
             .get_or_create(&NetworkLabels {
                 chain_id: self.chain_id.clone(),
             })
-            .set(network_data.namada_network_stake_threshold);
+            .set(epoch_data.namada_network_next_epoch_estimated_timestamp);
+    }
+    pub fn set_economics_metrics(&self, economics_data: &EconomicsMetricsData) {
         self.metrics
-            .namada_network_active_set_size
+            .namada_network_total_supply
             .metric
             .get_or_create(&NetworkLabels {
                 chain_id: self.chain_id.clone(),
             })
-            .set(network_data.namada_network_active_set_size);
+            .set(economics_data.namada_network_total_supply);
+        self.metrics
+            .namada_network_total_bonded_stake
+            .metric
+            .get_or_create(&NetworkLabels {
+                chain_id: self.chain_id.clone(),
+            })
+            .set(economics_data.namada_network_total_bonded_stake);
+        self.metrics
+            .namada_network_staking_ratio
+            .metric
+            .get_or_create(&NetworkLabels {
+                chain_id: self.chain_id.clone(),
+            })
+            .set(economics_data.namada_network_staking_ratio);
+        self.metrics
+            .namada_network_pos_inflation_rate
+            .metric
+            .get_or_create(&NetworkLabels {
+                chain_id: self.chain_id.clone(),
+            })
+            .set(economics_data.namada_network_pos_inflation_rate);
+        self.metrics
+            .namada_network_staking_rewards_rate
+            .metric
+            .get_or_create(&NetworkLabels {
+                chain_id: self.chain_id.clone(),
+            })
+            .set(economics_data.namada_network_staking_rewards_rate);
+        self.metrics
+            .namada_network_pgf_inflation_rate
+            .metric
+            .get_or_create(&NetworkLabels {
+                chain_id: self.chain_id.clone(),
+            })
+            .set(economics_data.namada_network_pgf_inflation_rate);
+        self.metrics
+            .namada_network_total_supply_raw
+            .metric
+            .get_or_create(&NetworkLabels {
+                chain_id: self.chain_id.clone(),
+            })
+            .set(economics_data.namada_network_total_supply_raw);
+        self.metrics
+            .namada_network_total_bonded_stake_raw
+            .metric
+            .get_or_create(&NetworkLabels {
+                chain_id: self.chain_id.clone(),
+            })
+            .set(economics_data.namada_network_total_bonded_stake_raw);
     }
-    pub fn set_node_metrics(&self, node_data: &NodeMetricsData) {
+    pub fn set_pgf_metrics(&self, pgf_data: &PgfMetricsData) {
+        for steward in &pgf_data.stewards {
+            self.metrics
+                .namada_pgf_steward
+                .metric
+                .get_or_create(&PgfStewardLabels {
+                    chain_id: self.chain_id.clone(),
+                    address: steward.address.clone(),
+                })
+                .set(steward.namada_pgf_steward);
+        }
+        for funding in &pgf_data.fundings {
+            self.metrics
+                .namada_pgf_continuous_funding_amount
+                .metric
+                .get_or_create(&PgfFundingLabels {
+                    chain_id: self.chain_id.clone(),
+                    target: funding.target.clone(),
+                    proposal_id: funding.proposal_id.to_string(),
+                })
+                .set(funding.namada_pgf_continuous_funding_amount);
+        }
         self.metrics
-            .namada_node_latest_block
+            .namada_pgf_stewards_count
+            .metric
+            .get_or_create(&NetworkLabels {
+                chain_id: self.chain_id.clone(),
+            })
+            .set(pgf_data.namada_pgf_stewards_count);
+        self.metrics
+            .namada_pgf_continuous_fundings_count
+            .metric
+            .get_or_create(&NetworkLabels {
+                chain_id: self.chain_id.clone(),
+            })
+            .set(pgf_data.namada_pgf_continuous_fundings_count);
+        self.metrics
+            .namada_pgf_continuous_funding_total
+            .metric
+            .get_or_create(&NetworkLabels {
+                chain_id: self.chain_id.clone(),
+            })
+            .set(pgf_data.namada_pgf_continuous_funding_total);
+        for payment in &pgf_data.retro_payments {
+            self.metrics
+                .namada_pgf_retro_payment_amount
+                .metric
+                .get_or_create(&PgfRetroPaymentLabels {
+                    chain_id: self.chain_id.clone(),
+                    target: payment.target.clone(),
+                    proposal_id: payment.proposal_id.to_string(),
+                    epoch: payment.epoch.to_string(),
+                })
+                .set(payment.namada_pgf_retro_payment_amount);
+        }
+        self.metrics
+            .namada_pgf_retro_payments_total
+            .metric
+            .get_or_create(&NetworkLabels {
+                chain_id: self.chain_id.clone(),
+            })
+            .set(pgf_data.namada_pgf_retro_payments_total);
+    }
+    pub fn set_balance_metrics(&self, balances_data: &[BalanceMetricsData]) {
+        for balance_data in balances_data {
+            let labels = BalanceLabels {
+                chain_id: self.chain_id.clone(),
+                address: balance_data.address.clone(),
+                token: balance_data.token.clone(),
+                alias: balance_data.alias.clone(),
+            };
+            self.metrics
+                .namada_account_balance
+                .metric
+                .get_or_create(&labels)
+                .set(balance_data.namada_account_balance);
+            self.metrics
+                .namada_account_balance_raw
+                .metric
+                .get_or_create(&labels)
+                .set(balance_data.namada_account_balance_raw);
+        }
+    }
+    pub fn set_masp_metrics(&self, masp_data: &MaspMetricsData) {
+        for token_data in &masp_data.tokens {
+            let labels = MaspTokenLabels {
+                chain_id: self.chain_id.clone(),
+                token: token_data.token.clone(),
+                name: token_data.name.clone(),
+            };
+            self.metrics
+                .namada_masp_total_shielded
+                .metric
+                .get_or_create(&labels)
+                .set(token_data.namada_masp_total_shielded);
+            self.metrics
+                .namada_masp_max_reward_rate
+                .metric
+                .get_or_create(&labels)
+                .set(token_data.namada_masp_max_reward_rate);
+            self.metrics
+                .namada_masp_kp_gain
+                .metric
+                .get_or_create(&labels)
+                .set(token_data.namada_masp_kp_gain);
+            self.metrics
+                .namada_masp_kd_gain
+                .metric
+                .get_or_create(&labels)
+                .set(token_data.namada_masp_kd_gain);
+            self.metrics
+                .namada_masp_locked_amount_target
+                .metric
+                .get_or_create(&labels)
+                .set(token_data.namada_masp_locked_amount_target);
+        }
+        self.metrics
+            .namada_masp_note_commitment_tree_size
+            .metric
+            .get_or_create(&NetworkLabels {
+                chain_id: self.chain_id.clone(),
+            })
+            .set(masp_data.namada_masp_note_commitment_tree_size);
+    }
+    pub fn set_ibc_metrics(&self, ibc_data: &IbcMetricsData) {
+        for client_data in &ibc_data.clients {
+            let labels = IbcClientLabels {
+                chain_id: self.chain_id.clone(),
+                client_id: client_data.client_id.clone(),
+                counterparty_chain_id: client_data.counterparty_chain_id.clone(),
+            };
+            self.metrics
+                .namada_ibc_client_latest_height
+                .metric
+                .get_or_create(&labels)
+                .set(client_data.namada_ibc_client_latest_height);
+            self.metrics
+                .namada_ibc_client_trusting_period_remaining_seconds
+                .metric
+                .get_or_create(&labels)
+                .set(client_data.namada_ibc_client_trusting_period_remaining_seconds);
+            self.metrics
+                .namada_ibc_client_frozen
+                .metric
+                .get_or_create(&labels)
+                .set(client_data.namada_ibc_client_frozen);
+        }
+        for channel_data in &ibc_data.channels {
+            self.metrics
+                .namada_ibc_channel_state
+                .metric
+                .get_or_create(&IbcChannelLabels {
+                    chain_id: self.chain_id.clone(),
+                    port_id: channel_data.port_id.clone(),
+                    channel_id: channel_data.channel_id.clone(),
+                })
+                .set(channel_data.namada_ibc_channel_state);
+        }
+    }
+    pub fn set_token_metrics(&self, tokens_data: &[TokenMetricsData]) {
+        for token_data in tokens_data {
+            let labels = TokenLabels {
+                chain_id: self.chain_id.clone(),
+                token: token_data.token.clone(),
+                alias: token_data.alias.clone(),
+            };
+            self.metrics
+                .namada_token_total_supply
+                .metric
+                .get_or_create(&labels)
+                .set(token_data.namada_token_total_supply);
+            self.metrics
+                .namada_token_denomination
+                .metric
+                .get_or_create(&labels)
+                .set(token_data.namada_token_denomination);
+            self.metrics
+                .namada_token_mint_limit
+                .metric
+                .get_or_create(&labels)
+                .set(token_data.namada_token_mint_limit);
+            self.metrics
+                .namada_token_throughput_limit
+                .metric
+                .get_or_create(&labels)
+                .set(token_data.namada_token_throughput_limit);
+            self.metrics
+                .namada_token_minted_amount
+                .metric
+                .get_or_create(&labels)
+                .set(token_data.namada_token_minted_amount);
+            self.metrics
+                .namada_token_total_supply_raw
+                .metric
+                .get_or_create(&labels)
+                .set(token_data.namada_token_total_supply_raw);
+        }
+    }
+    pub fn set_version_metrics(&self, version_data: &VersionMetricsData) {
+        self.metrics
+            .namada_node_version_info
+            .metric
+            .get_or_create(&VersionLabels {
+                chain_id: self.chain_id.clone(),
+                node_id: version_data.node_id.clone(),
+                moniker: version_data.moniker.clone(),
+                cometbft_version: version_data.cometbft_version.clone(),
+                namada_version: version_data.namada_version.clone(),
+                protocol_version: version_data.protocol_version.clone(),
+            })
+            .set(1);
+        self.metrics
+            .namada_node_version_mismatch
             .metric
             .get_or_create(&NodeLabels {
                 chain_id: self.chain_id.clone(),
-                node_id: node_data.node_id.clone(),
-                moniker: node_data.moniker.clone(),
+                node_id: version_data.node_id.clone(),
+                moniker: version_data.moniker.clone(),
             })
-            .set(node_data.namada_node_latest_block);
+            .set(version_data.namada_node_version_mismatch);
+    }
+    pub fn set_upgrade_metrics(&self, upgrade_data: &UpgradeMetricsData) {
         self.metrics
-            .namada_validator_missed_blocks
+            .namada_network_upgrade_height
+            .metric
+            .get_or_create(&UpgradeLabels {
+                chain_id: self.chain_id.clone(),
+                name: upgrade_data.name.clone(),
+            })
+            .set(upgrade_data.namada_network_upgrade_height);
+        self.metrics
+            .namada_network_upgrade_blocks_remaining
+            .metric
+            .get_or_create(&UpgradeLabels {
+                chain_id: self.chain_id.clone(),
+                name: upgrade_data.name.clone(),
+            })
+            .set(upgrade_data.namada_network_upgrade_blocks_remaining);
+        self.metrics
+            .namada_network_upgrade_estimated_timestamp
+            .metric
+            .get_or_create(&UpgradeLabels {
+                chain_id: self.chain_id.clone(),
+                name: upgrade_data.name.clone(),
+            })
+            .set(upgrade_data.namada_network_upgrade_estimated_timestamp);
+        self.metrics
+            .namada_network_average_block_time_seconds
             .metric
             .get_or_create(&NetworkLabels {
                 chain_id: self.chain_id.clone(),
             })
-            .set(node_data.namada_node_latest_block);
+            .set(upgrade_data.namada_network_average_block_time_seconds);
+    }
+    pub fn set_tx_metrics(&self, tx_data: &TxMetricsData) {
+        self.metrics
+            .namada_block_follower_height
+            .metric
+            .get_or_create(&NetworkLabels {
+                chain_id: self.chain_id.clone(),
+            })
+            .set(tx_data.namada_block_follower_height);
+        self.metrics
+            .namada_block_last_txs
+            .metric
+            .get_or_create(&NetworkLabels {
+                chain_id: self.chain_id.clone(),
+            })
+            .set(tx_data.namada_block_last_txs);
+        self.metrics
+            .namada_block_last_gas_used
+            .metric
+            .get_or_create(&NetworkLabels {
+                chain_id: self.chain_id.clone(),
+            })
+            .set(tx_data.namada_block_last_gas_used);
+        self.metrics
+            .namada_block_gas_limit
+            .metric
+            .get_or_create(&NetworkLabels {
+                chain_id: self.chain_id.clone(),
+            })
+            .set(tx_data.namada_block_gas_limit);
+        self.metrics
+            .namada_block_processed
+            .metric
+            .get_or_create(&NetworkLabels {
+                chain_id: self.chain_id.clone(),
+            })
+            .inc_by(tx_data.namada_block_processed);
+        self.metrics
+            .namada_block_gas_used
+            .metric
+            .get_or_create(&NetworkLabels {
+                chain_id: self.chain_id.clone(),
+            })
+            .inc_by(tx_data.namada_block_gas_used);
+        self.metrics
+            .namada_block_txs
+            .metric
+            .get_or_create(&TxResultLabels {
+                chain_id: self.chain_id.clone(),
+                result: "success".to_string(),
+            })
+            .inc_by(tx_data.namada_block_txs_succeeded);
+        self.metrics
+            .namada_block_txs
+            .metric
+            .get_or_create(&TxResultLabels {
+                chain_id: self.chain_id.clone(),
+                result: "failure".to_string(),
+            })
+            .inc_by(tx_data.namada_block_txs_failed);
+        for (tx_type, count) in &tx_data.txs_by_type {
+            self.metrics
+                .namada_block_inner_txs
+                .metric
+                .get_or_create(&TxTypeLabels {
+                    chain_id: self.chain_id.clone(),
+                    tx_type: tx_type.clone(),
+                })
+                .inc_by(*count);
+        }
+        for (event_type, count) in &tx_data.validator_events {
+            self.metrics
+                .namada_validator_tx_events
+                .metric
+                .get_or_create(&ValidatorEventLabels {
+                    chain_id: self.chain_id.clone(),
+                    validator_tm_address: self.tm_address.clone(),
+                    event_type: event_type.clone(),
+                })
+                .inc_by(*count);
+        }
+        for (event_type, amount) in &tx_data.validator_event_amounts {
+            self.metrics
+                .namada_validator_tx_event_amount
+                .metric
+                .get_or_create(&ValidatorEventLabels {
+                    chain_id: self.chain_id.clone(),
+                    validator_tm_address: self.tm_address.clone(),
+                    event_type: event_type.clone(),
+                })
+                .inc_by(*amount);
+        }
+    }
+    pub fn set_slashing_metrics(&self, slashing_data: &SlashingMetricsData) {
+        self.metrics
+            .namada_validator_voting_power_fraction
+            .metric
+            .get_or_create(&ValidatorLabels {
+                chain_id: self.chain_id.clone(),
+                validator_tm_address: self.tm_address.clone(),
+                validator_hash_address: slashing_data.validator_address_hash.clone(),
+            })
+            .set(slashing_data.namada_validator_voting_power_fraction);
+        self.metrics
+            .namada_validator_cubic_slash_window_infracting_fraction
+            .metric
+            .get_or_create(&ValidatorLabels {
+                chain_id: self.chain_id.clone(),
+                validator_tm_address: self.tm_address.clone(),
+                validator_hash_address: slashing_data.validator_address_hash.clone(),
+            })
+            .set(slashing_data.namada_validator_cubic_slash_window_infracting_fraction);
+        self.metrics
+            .namada_validator_hypothetical_duplicate_vote_slash_rate
+            .metric
+            .get_or_create(&ValidatorLabels {
+                chain_id: self.chain_id.clone(),
+                validator_tm_address: self.tm_address.clone(),
+                validator_hash_address: slashing_data.validator_address_hash.clone(),
+            })
+            .set(slashing_data.namada_validator_hypothetical_duplicate_vote_slash_rate);
+        self.metrics
+            .namada_validator_hypothetical_light_client_attack_slash_rate
+            .metric
+            .get_or_create(&ValidatorLabels {
+                chain_id: self.chain_id.clone(),
+                validator_tm_address: self.tm_address.clone(),
+                validator_hash_address: slashing_data.validator_address_hash.clone(),
+            })
+            .set(slashing_data.namada_validator_hypothetical_light_client_attack_slash_rate);
+        self.metrics
+            .namada_network_enqueued_slashes
+            .metric
+            .get_or_create(&NetworkLabels {
+                chain_id: self.chain_id.clone(),
+            })
+            .set(slashing_data.namada_network_enqueued_slashes);
+        self.metrics
+            .namada_validator_incoming_redelegated_amount
+            .metric
+            .get_or_create(&ValidatorLabels {
+                chain_id: self.chain_id.clone(),
+                validator_tm_address: self.tm_address.clone(),
+                validator_hash_address: slashing_data.validator_address_hash.clone(),
+            })
+            .set(slashing_data.namada_validator_incoming_redelegated_amount);
+        self.metrics
+            .namada_validator_outgoing_redelegated_amount
+            .metric
+            .get_or_create(&ValidatorLabels {
+                chain_id: self.chain_id.clone(),
+                validator_tm_address: self.tm_address.clone(),
+                validator_hash_address: slashing_data.validator_address_hash.clone(),
+            })
+            .set(slashing_data.namada_validator_outgoing_redelegated_amount);
+    }
+    pub fn set_healthcheck_metrics(&mut self, healthcheck_data: &HealthCheckMetricsData) {
+        self.metrics
+            .namada_exporter_healthcheck_pings
+            .metric
+            .get_or_create(&PingResultLabels {
+                chain_id: self.chain_id.clone(),
+                result: "success".to_string(),
+            })
+            .inc_by(healthcheck_data.namada_exporter_healthcheck_pings_succeeded);
+        self.metrics
+            .namada_exporter_healthcheck_pings
+            .metric
+            .get_or_create(&PingResultLabels {
+                chain_id: self.chain_id.clone(),
+                result: "failure".to_string(),
+            })
+            .inc_by(healthcheck_data.namada_exporter_healthcheck_pings_failed);
+        self.metrics
+            .namada_exporter_healthcheck_last_success_timestamp
+            .metric
+            .get_or_create(&NetworkLabels {
+                chain_id: self.chain_id.clone(),
+            })
+            .set(healthcheck_data.namada_exporter_healthcheck_last_success_timestamp);
+        // The histogram lives in the health checker across scrapes, so it is registered as is
+        // instead of being recreated from the collected data like the other metrics.
+        self.registry
+            .sub_registry_with_label(("chain_id".into(), self.chain_id.clone().into()))
+            .register(
+                "namada_exporter_healthcheck_ping_latency_seconds",
+                "Latency of health check ping attempts in seconds",
+                healthcheck_data
+                    .namada_exporter_healthcheck_ping_latency_seconds
+                    .clone(),
+            );
     }
     pub fn render(&self) -> String {
         let mut buffer = String::new();
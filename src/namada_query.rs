@@ -1,15 +1,41 @@
-use namada_sdk::address::Address;
-use namada_sdk::key::PublicKeyTmRawHash;
+use borsh::BorshDeserialize;
+use log::error;
+use namada_sdk::address::{Address, MASP};
+use namada_sdk::dec::Dec;
+use namada_sdk::governance::storage::keys::get_counter_key;
+use namada_sdk::governance::storage::proposal::{PGFAction, ProposalType, StorageProposal};
+use namada_sdk::governance::utils::TallyResult;
+use namada_sdk::ibc::clients::tendermint::types::{
+    ClientState as TmClientState, ConsensusState as TmConsensusState,
+};
+use namada_sdk::ibc::core::channel::types::channel::{ChannelEnd, State as ChannelState};
+use namada_sdk::ibc::core::host::types::identifiers::{ChannelId, ClientId, PortId};
+use namada_sdk::ibc::primitives::proto::{Any, Protobuf};
+use namada_sdk::ibc::storage::{
+    channel_key, client_state_key, consensus_state_key, mint_amount_key, mint_limit_key,
+    throughput_limit_key,
+};
+use namada_sdk::key::{common, PublicKeyTmRawHash};
+use namada_sdk::masp_primitives::merkle_tree::CommitmentTree;
+use namada_sdk::masp_primitives::sapling::Node;
+use namada_sdk::parameters::storage::get_max_block_gas_key;
 use namada_sdk::proof_of_stake::types::{
     CommissionPair, ValidatorMetaData, ValidatorState as ValidatorStateType,
 };
 use namada_sdk::proof_of_stake::{self, PosParams};
+use namada_sdk::queries::RPC;
 use namada_sdk::rpc;
-use namada_sdk::rpc::query_storage_value;
-use namada_sdk::storage::DbKeySeg;
-use namada_sdk::uint::Uint;
+use namada_sdk::rpc::{query_storage_value, query_storage_value_bytes};
+use namada_sdk::storage::{DbKeySeg, Key};
+use namada_sdk::token::storage_key::masp_commitment_tree_key;
+use namada_sdk::token::Amount;
+use namada_sdk::tx::Tx;
+use std::collections::{HashMap, HashSet};
 use std::error::Error;
 use std::str::FromStr;
+use std::time::Duration;
+use tendermint::block::Height;
+use tendermint_rpc::endpoint::abci_info::AbciInfo;
 use tendermint_rpc::endpoint::status::Response;
 use tendermint_rpc::{Client, HttpClient, Url};
 use tokio::runtime::Handle;
@@ -42,10 +68,12 @@ impl ValidatorState {
 pub struct ValidatorData {
     #[allow(dead_code)]
     pub metadata: Option<ValidatorMetaData>,
-    pub stake: String,
+    pub stake: Amount,
     pub commission: CommissionPair,
     pub address_hash: String,
     pub missed_blocks: Option<u64>,
+    pub pending_consensus_key_epoch: Option<u64>,
+    pub pending_consensus_key_hash: Option<String>,
     #[allow(dead_code)]
     pub address: String,
     pub state: ValidatorState,
@@ -53,7 +81,148 @@ pub struct ValidatorData {
 #[derive(Debug)]
 pub struct ValidatorStake {
     pub address: String,
-    pub stake: Uint,
+    pub stake: Amount,
+}
+
+#[derive(Debug)]
+pub struct EpochProgress {
+    pub first_block_height: u64,
+    pub first_block_time: i64,
+    pub min_num_of_blocks: u64,
+    pub min_duration: u64,
+}
+
+#[derive(Debug)]
+pub struct EconomicsData {
+    pub total_supply: Amount,
+    pub total_bonded_stake: Amount,
+    pub pos_inflation_rate: Dec,
+    pub staking_rewards_rate: Dec,
+    pub pgf_inflation_rate: Dec,
+}
+
+#[derive(Debug)]
+pub struct PgfFunding {
+    pub target: String,
+    pub proposal_id: u64,
+    pub amount: Amount,
+}
+
+#[derive(Debug)]
+pub struct PgfRetroPayment {
+    pub target: String,
+    pub proposal_id: u64,
+    /// Activation epoch of the proposal, in which the payment was made
+    pub epoch: u64,
+    pub amount: Amount,
+}
+
+#[derive(Debug)]
+pub struct PgfData {
+    pub stewards: Vec<String>,
+    pub fundings: Vec<PgfFunding>,
+    pub retro_payments: Vec<PgfRetroPayment>,
+}
+
+#[derive(Debug)]
+pub struct TokenBalance {
+    pub token: String,
+    pub amount: Amount,
+    pub denom: u8,
+}
+
+#[derive(Debug)]
+pub struct MaspRewards {
+    pub max_reward_rate: Dec,
+    pub kp_gain: Dec,
+    pub kd_gain: Dec,
+    pub locked_amount_target: Amount,
+}
+
+#[derive(Debug)]
+pub struct MaspToken {
+    pub token: String,
+    pub name: String,
+    pub denom: u8,
+    pub total_shielded: Amount,
+    pub rewards: Option<MaspRewards>,
+}
+
+#[derive(Debug)]
+pub struct MaspData {
+    pub tokens: Vec<MaspToken>,
+    pub note_commitment_tree_size: u64,
+}
+
+#[derive(Debug)]
+pub struct IbcClient {
+    pub client_id: String,
+    pub counterparty_chain_id: String,
+    pub latest_height: u64,
+    pub latest_consensus_time: i64,
+    pub trusting_period: Duration,
+    pub frozen: bool,
+}
+
+#[derive(Debug)]
+pub struct IbcChannel {
+    pub port_id: String,
+    pub channel_id: String,
+    pub state: u8,
+}
+
+#[derive(Debug)]
+pub struct TokenSupply {
+    pub token: String,
+    pub denom: u8,
+    pub total_supply: Amount,
+    pub mint_limit: Option<Amount>,
+    pub throughput_limit: Option<Amount>,
+    pub minted_amount: Option<Amount>,
+}
+
+#[derive(Debug)]
+pub struct InnerTx {
+    /// Tag of the wasm code, e.g. `tx_transfer.wasm`
+    pub code_tag: Option<String>,
+    pub data: Option<Vec<u8>>,
+}
+
+#[derive(Debug)]
+pub struct BlockTx {
+    pub hash: String,
+    pub succeeded: bool,
+    pub gas_used: u64,
+    pub inner_txs: Vec<InnerTx>,
+}
+
+#[derive(Debug)]
+pub struct BlockTxs {
+    pub time: i64,
+    pub txs: Vec<BlockTx>,
+}
+
+#[derive(Debug)]
+pub struct EnqueuedSlash {
+    pub validator: String,
+    pub infraction_epoch: u64,
+    pub stake: Amount,
+    pub total_stake: Amount,
+}
+
+#[derive(Debug)]
+pub struct SlashExposure {
+    pub epoch: u64,
+    pub stake: Amount,
+    pub total_stake: Amount,
+    /// Slashes within the cubic slashing window, once per validator and infraction epoch
+    pub enqueued_slashes: Vec<EnqueuedSlash>,
+    /// All enqueued slashes, including those outside the window
+    pub enqueued_slashes_total: usize,
+    /// Bonds redelegated to the validator from other validators
+    pub incoming_redelegated: Amount,
+    /// Bonds redelegated from the validator to other validators
+    pub outgoing_redelegated: Amount,
 }
 
 #[derive(Clone)]
@@ -85,6 +254,7 @@ impl Query {
     pub async fn query_validators_async(
         &self,
         address: &str,
+        pos_params: &PosParams,
     ) -> Result<Option<ValidatorData>, Box<dyn Error>> {
         let addr: Address = Address::from_str(&address)?;
         let commission = rpc::query_commission_rate(&self.client, &addr, None).await?;
@@ -97,13 +267,32 @@ impl Query {
         let liveness_key = proof_of_stake::storage_key::liveness_sum_missed_votes_key();
         let val_key = rpc::query_validator_consensus_keys(&self.client, &addr)
             .await?
-            .expect("Could not get validator consensus key");
+            .ok_or(format!("Could not query consensus key of {}", address))?;
 
         let missed_key = liveness_key
-            .push(&DbKeySeg::StringSeg("data".to_string()))
-            .expect("Could not create storage key")
-            .push(&DbKeySeg::AddressSeg(addr.clone()))
-            .expect("Could not create storage key");
+            .push(&DbKeySeg::StringSeg("data".to_string()))?
+            .push(&DbKeySeg::AddressSeg(addr.clone()))?;
+
+        // Consensus key changes are written at the pipeline epoch, so look for a different key
+        // in the epochs between the current one and the pipeline.
+        let consensus_key_data_key =
+            proof_of_stake::storage_key::validator_consensus_key_key(&addr)
+                .push(&DbKeySeg::StringSeg("data".to_string()))?
+                .push(&DbKeySeg::StringSeg("data".to_string()))?;
+        let mut pending_consensus_key_epoch = None;
+        let mut pending_consensus_key_hash = None;
+        for offset in 1..=pos_params.pipeline_len {
+            let pending_epoch = epoch + offset;
+            let pending_key = consensus_key_data_key.push(&pending_epoch)?;
+            let pending_consensus_key: Result<common::PublicKey, namada_sdk::error::Error> =
+                query_storage_value(&self.client, &pending_key).await;
+            if let Ok(pending_consensus_key) = pending_consensus_key {
+                if pending_consensus_key != val_key {
+                    pending_consensus_key_epoch = Some(pending_epoch.0);
+                    pending_consensus_key_hash = Some(pending_consensus_key.tm_raw_hash());
+                }
+            }
+        }
 
         let (state_or_none, _) = rpc::get_validator_state(&self.client, &addr, None).await?;
         let missed_block: Result<u64, namada_sdk::error::Error> =
@@ -126,9 +315,11 @@ impl Query {
         let validator_data = ValidatorData {
             commission,
             metadata: validator_metadata_or_none,
-            stake: stake.to_string(),
+            stake,
             address_hash: val_key.tm_raw_hash(),
             missed_blocks: missed_blocks_maybe,
+            pending_consensus_key_epoch,
+            pending_consensus_key_hash,
             address: address.to_string(),
             state,
         };
@@ -138,10 +329,11 @@ impl Query {
     pub fn query_validators_data(
         &self,
         address: &str,
+        pos_params: &PosParams,
     ) -> Result<Option<ValidatorData>, Box<dyn Error>> {
         let result = block_in_place(|| {
             let runtime_handle = Handle::current();
-            runtime_handle.block_on(self.query_validators_async(address))
+            runtime_handle.block_on(self.query_validators_async(address, pos_params))
         })?;
         Ok(result)
     }
@@ -154,7 +346,7 @@ impl Query {
             .iter()
             .map(|val| ValidatorStake {
                 address: val.address.clone().to_string(),
-                stake: val.bonded_stake.clone().into(),
+                stake: val.bonded_stake,
             })
             .collect();
 
@@ -190,4 +382,660 @@ impl Query {
         })?;
         Ok(result)
     }
+    pub async fn query_epoch_progress_async(&self) -> Result<EpochProgress, Box<dyn Error>> {
+        let (first_block_height, epoch_duration) = rpc::query_next_epoch_info(&self.client).await?;
+        let first_block = self
+            .client
+            .block(Height::try_from(first_block_height.0)?)
+            .await?;
+        Ok(EpochProgress {
+            first_block_height: first_block_height.0,
+            first_block_time: first_block.block.header.time.unix_timestamp(),
+            min_num_of_blocks: epoch_duration.min_num_of_blocks,
+            min_duration: epoch_duration.min_duration.0,
+        })
+    }
+    pub fn query_epoch_progress(&self) -> Result<EpochProgress, Box<dyn Error>> {
+        let result = block_in_place(|| {
+            let runtime_handle = Handle::current();
+            runtime_handle.block_on(self.query_epoch_progress_async())
+        })?;
+        Ok(result)
+    }
+    pub async fn query_economics_async(&self) -> Result<EconomicsData, Box<dyn Error>> {
+        let native_token = rpc::query_native_token(&self.client).await?;
+        let total_supply = rpc::get_token_total_supply(&self.client, &native_token).await?;
+        let epoch = rpc::query_epoch(&self.client).await?;
+        let total_bonded_stake = rpc::get_total_staked_tokens(&self.client, epoch).await?;
+        let rewards_rate = rpc::get_staking_rewards_rate(&self.client).await?;
+        let pgf_parameters = rpc::query_pgf_parameters(&self.client).await;
+        Ok(EconomicsData {
+            total_supply,
+            total_bonded_stake,
+            pos_inflation_rate: rewards_rate.inflation_rate,
+            staking_rewards_rate: rewards_rate.staking_rewards_rate,
+            pgf_inflation_rate: pgf_parameters.pgf_inflation_rate,
+        })
+    }
+    pub fn query_economics(&self) -> Result<EconomicsData, Box<dyn Error>> {
+        let result = block_in_place(|| {
+            let runtime_handle = Handle::current();
+            runtime_handle.block_on(self.query_economics_async())
+        })?;
+        Ok(result)
+    }
+    pub async fn query_pgf_async(&self, retro_proposals: u64) -> Result<PgfData, Box<dyn Error>> {
+        let stewards = rpc::query_pgf_stewards(&self.client)
+            .await
+            .iter()
+            .map(|steward| steward.address.to_string())
+            .collect();
+        let fundings = rpc::query_pgf_fundings(&self.client)
+            .await
+            .iter()
+            .map(|funding| PgfFunding {
+                target: funding.detail.target.target(),
+                proposal_id: funding.detail.proposal_id,
+                amount: funding.detail.target.amount(),
+            })
+            .collect();
+
+        // Retroactive payments are one-off transfers made when a passed PGF proposal is activated.
+        // They are not kept in the PGF storage, only in the proposal, so scan the latest proposals.
+        let epoch = rpc::query_epoch(&self.client).await?;
+        let proposal_counter: u64 = query_storage_value(&self.client, &get_counter_key()).await?;
+        let mut retro_payments = Vec::new();
+        for proposal_id in (0..proposal_counter).rev().take(retro_proposals as usize) {
+            let Some(proposal) = rpc::query_proposal_by_id(&self.client, proposal_id).await? else {
+                continue;
+            };
+            if !is_activated_pgf_payment(&proposal, epoch.0) {
+                continue;
+            }
+            let passed = rpc::query_proposal_result(&self.client, proposal_id)
+                .await?
+                .is_some_and(|result| matches!(result.result, TallyResult::Passed));
+            retro_payments.extend(proposal_retro_payments(proposal_id, &proposal, passed));
+        }
+        Ok(PgfData {
+            stewards,
+            fundings,
+            retro_payments,
+        })
+    }
+    pub fn query_pgf(&self, retro_proposals: u64) -> Result<PgfData, Box<dyn Error>> {
+        let result = block_in_place(|| {
+            let runtime_handle = Handle::current();
+            runtime_handle.block_on(self.query_pgf_async(retro_proposals))
+        })?;
+        Ok(result)
+    }
+    pub async fn query_balance_async(
+        &self,
+        owner: &str,
+        token: Option<&str>,
+    ) -> Result<TokenBalance, Box<dyn Error>> {
+        let owner = Address::from_str(owner)?;
+        let token = match token {
+            Some(token) => Address::from_str(token)?,
+            None => rpc::query_native_token(&self.client).await?,
+        };
+        let amount = rpc::get_token_balance(&self.client, &token, &owner, None).await?;
+        let denom = rpc::query_denom(&self.client, &token)
+            .await
+            .ok_or(format!("Could not query denomination of {}", token))?
+            .0;
+        Ok(TokenBalance {
+            token: token.to_string(),
+            amount,
+            denom,
+        })
+    }
+    pub fn query_balance(
+        &self,
+        owner: &str,
+        token: Option<&str>,
+    ) -> Result<TokenBalance, Box<dyn Error>> {
+        let result = block_in_place(|| {
+            let runtime_handle = Handle::current();
+            runtime_handle.block_on(self.query_balance_async(owner, token))
+        })?;
+        Ok(result)
+    }
+    async fn query_masp_token_async(
+        &self,
+        token: Address,
+        name: String,
+        rewards: Option<MaspRewards>,
+    ) -> Result<MaspToken, Box<dyn Error>> {
+        let total_shielded = rpc::get_token_balance(&self.client, &token, &MASP, None).await?;
+        let denom = rpc::query_denom(&self.client, &token)
+            .await
+            .ok_or(format!("Could not query denomination of {}", token))?
+            .0;
+        Ok(MaspToken {
+            token: token.to_string(),
+            name,
+            denom,
+            total_shielded,
+            rewards,
+        })
+    }
+    pub async fn query_masp_async(
+        &self,
+        extra_tokens: &[String],
+    ) -> Result<MaspData, Box<dyn Error>> {
+        let mut tokens = Vec::new();
+        for reward_token in rpc::query_masp_reward_tokens(&self.client).await? {
+            let rewards = MaspRewards {
+                max_reward_rate: reward_token.max_reward_rate,
+                kp_gain: reward_token.kp_gain,
+                kd_gain: reward_token.kd_gain,
+                locked_amount_target: Amount::from_uint(reward_token.locked_amount_target, 0)?,
+            };
+            let address = reward_token.address.clone();
+            match self
+                .query_masp_token_async(reward_token.address, reward_token.name, Some(rewards))
+                .await
+            {
+                Ok(token) => tokens.push(token),
+                Err(e) => error!("Could not query masp token {}: {}", address, e),
+            }
+        }
+        for extra_token in extra_tokens {
+            let address = Address::from_str(extra_token)?;
+            if tokens
+                .iter()
+                .any(|token| token.token == address.to_string())
+            {
+                continue;
+            }
+            match self
+                .query_masp_token_async(address, extra_token.clone(), None)
+                .await
+            {
+                Ok(token) => tokens.push(token),
+                Err(e) => error!("Could not query masp token {}: {}", extra_token, e),
+            }
+        }
+        let commitment_tree: CommitmentTree<Node> =
+            query_storage_value(&self.client, &masp_commitment_tree_key()).await?;
+        Ok(MaspData {
+            tokens,
+            note_commitment_tree_size: commitment_tree.size() as u64,
+        })
+    }
+    pub fn query_masp(&self, extra_tokens: &[String]) -> Result<MaspData, Box<dyn Error>> {
+        let result = block_in_place(|| {
+            let runtime_handle = Handle::current();
+            runtime_handle.block_on(self.query_masp_async(extra_tokens))
+        })?;
+        Ok(result)
+    }
+    pub async fn query_ibc_client_async(
+        &self,
+        client_id: &str,
+    ) -> Result<IbcClient, Box<dyn Error>> {
+        let id = ClientId::from_str(client_id)?;
+        let (client_state_bytes, _) =
+            query_storage_value_bytes(&self.client, &client_state_key(&id), None, false).await?;
+        let client_state = decode_client_state(
+            &client_state_bytes.ok_or(format!("Client state of {} not found", client_id))?,
+        )?;
+        let (consensus_state_bytes, _) = query_storage_value_bytes(
+            &self.client,
+            &consensus_state_key(&id, client_state.latest_height),
+            None,
+            false,
+        )
+        .await?;
+        let consensus_state = decode_consensus_state(
+            &consensus_state_bytes.ok_or(format!("Consensus state of {} not found", client_id))?,
+        )?;
+        Ok(IbcClient {
+            client_id: client_id.to_string(),
+            counterparty_chain_id: client_state.chain_id.to_string(),
+            latest_height: client_state.latest_height.revision_height(),
+            latest_consensus_time: consensus_state.timestamp.unix_timestamp(),
+            trusting_period: client_state.trusting_period,
+            frozen: client_state.frozen_height.is_some(),
+        })
+    }
+    pub fn query_ibc_client(&self, client_id: &str) -> Result<IbcClient, Box<dyn Error>> {
+        let result = block_in_place(|| {
+            let runtime_handle = Handle::current();
+            runtime_handle.block_on(self.query_ibc_client_async(client_id))
+        })?;
+        Ok(result)
+    }
+    pub async fn query_ibc_channel_async(
+        &self,
+        port_id: &str,
+        channel_id: &str,
+    ) -> Result<IbcChannel, Box<dyn Error>> {
+        let key = channel_key(
+            &PortId::from_str(port_id)?,
+            &ChannelId::from_str(channel_id)?,
+        );
+        let (channel_bytes, _) = query_storage_value_bytes(&self.client, &key, None, false).await?;
+        let state = match channel_bytes {
+            Some(bytes) => match ChannelEnd::decode_vec(&bytes)?.state {
+                ChannelState::Uninitialized => 0,
+                ChannelState::Init => 1,
+                ChannelState::TryOpen => 2,
+                ChannelState::Open => 3,
+                ChannelState::Closed => 4,
+            },
+            None => 0,
+        };
+        Ok(IbcChannel {
+            port_id: port_id.to_string(),
+            channel_id: channel_id.to_string(),
+            state,
+        })
+    }
+    pub fn query_ibc_channel(
+        &self,
+        port_id: &str,
+        channel_id: &str,
+    ) -> Result<IbcChannel, Box<dyn Error>> {
+        let result = block_in_place(|| {
+            let runtime_handle = Handle::current();
+            runtime_handle.block_on(self.query_ibc_channel_async(port_id, channel_id))
+        })?;
+        Ok(result)
+    }
+    pub async fn query_token_supply_async(
+        &self,
+        token: &str,
+    ) -> Result<TokenSupply, Box<dyn Error>> {
+        let address = Address::from_str(token)?;
+        let total_supply = rpc::get_token_total_supply(&self.client, &address).await?;
+        let denom = rpc::query_denom(&self.client, &address)
+            .await
+            .ok_or(format!("Could not query denomination of {}", address))?
+            .0;
+        let mint_limit: Option<Amount> =
+            query_storage_value(&self.client, &mint_limit_key(&address))
+                .await
+                .ok();
+        let throughput_limit: Option<Amount> =
+            query_storage_value(&self.client, &throughput_limit_key(&address))
+                .await
+                .ok();
+        let minted_amount: Option<Amount> =
+            query_storage_value(&self.client, &mint_amount_key(&address))
+                .await
+                .ok();
+        Ok(TokenSupply {
+            token: token.to_string(),
+            denom,
+            total_supply,
+            mint_limit,
+            throughput_limit,
+            minted_amount,
+        })
+    }
+    pub fn query_token_supply(&self, token: &str) -> Result<TokenSupply, Box<dyn Error>> {
+        let result = block_in_place(|| {
+            let runtime_handle = Handle::current();
+            runtime_handle.block_on(self.query_token_supply_async(token))
+        })?;
+        Ok(result)
+    }
+    pub async fn abci_info_async(&self) -> Result<AbciInfo, Box<dyn Error>> {
+        let result = self.client.abci_info().await?;
+        Ok(result)
+    }
+    pub fn abci_info(&self) -> Result<AbciInfo, Box<dyn Error>> {
+        let result = block_in_place(|| {
+            let runtime_handle = Handle::current();
+            runtime_handle.block_on(self.abci_info_async())
+        })?;
+        Ok(result)
+    }
+    pub async fn query_block_time_async(&self, height: u64) -> Result<i64, Box<dyn Error>> {
+        let block = self.client.block(Height::try_from(height)?).await?;
+        Ok(block.block.header.time.unix_timestamp())
+    }
+    pub fn query_block_time(&self, height: u64) -> Result<i64, Box<dyn Error>> {
+        let result = block_in_place(|| {
+            let runtime_handle = Handle::current();
+            runtime_handle.block_on(self.query_block_time_async(height))
+        })?;
+        Ok(result)
+    }
+    pub async fn query_block_txs_async(&self, height: u64) -> Result<BlockTxs, Box<dyn Error>> {
+        let height = Height::try_from(height)?;
+        let block = self.client.block(height).await?;
+        let block_results = self.client.block_results(height).await?;
+        let tx_results = block_results.txs_results.unwrap_or_default();
+        let txs = block
+            .block
+            .data
+            .iter()
+            .zip(tx_results.iter())
+            .map(|(tx_bytes, tx_result)| {
+                let (hash, inner_txs) = match Tx::try_from(tx_bytes.as_slice()) {
+                    Ok(tx) => {
+                        let inner_txs = tx
+                            .header
+                            .batch
+                            .iter()
+                            .map(|cmt| InnerTx {
+                                code_tag: tx
+                                    .get_section(cmt.code_sechash())
+                                    .and_then(|section| section.code_sec())
+                                    .and_then(|code| code.tag),
+                                data: tx.data(cmt),
+                            })
+                            .collect();
+                        (tx.header_hash().to_string(), inner_txs)
+                    }
+                    Err(_) => (String::new(), vec![]),
+                };
+                BlockTx {
+                    hash,
+                    succeeded: tx_result.code.is_ok(),
+                    gas_used: tx_result.gas_used.max(0) as u64,
+                    inner_txs,
+                }
+            })
+            .collect();
+        Ok(BlockTxs {
+            time: block.block.header.time.unix_timestamp(),
+            txs,
+        })
+    }
+    pub fn query_block_txs(&self, height: u64) -> Result<BlockTxs, Box<dyn Error>> {
+        let result = block_in_place(|| {
+            let runtime_handle = Handle::current();
+            runtime_handle.block_on(self.query_block_txs_async(height))
+        })?;
+        Ok(result)
+    }
+    pub async fn query_max_block_gas_async(&self) -> Result<u64, Box<dyn Error>> {
+        let result = query_storage_value(&self.client, &get_max_block_gas_key()).await?;
+        Ok(result)
+    }
+    pub fn query_max_block_gas(&self) -> Result<u64, Box<dyn Error>> {
+        let result = block_in_place(|| {
+            let runtime_handle = Handle::current();
+            runtime_handle.block_on(self.query_max_block_gas_async())
+        })?;
+        Ok(result)
+    }
+    pub async fn query_slash_exposure_async(
+        &self,
+        address: &str,
+        pos_params: &PosParams,
+    ) -> Result<SlashExposure, Box<dyn Error>> {
+        let addr = Address::from_str(address)?;
+        let epoch = rpc::query_epoch(&self.client).await?;
+        let stake = rpc::get_validator_stake(&self.client, epoch, &addr).await?;
+        let total_stake = rpc::get_total_staked_tokens(&self.client, epoch).await?;
+        let window = pos_params.owned.cubic_slashing_window_length;
+        // Only slashes within the cubic slashing window count, once per validator and infraction
+        // epoch, so the stakes are queried for those only and total stakes once per epoch.
+        let mut total_stakes = HashMap::from([(epoch, total_stake)]);
+        let mut seen = HashSet::new();
+        let mut enqueued_slashes = Vec::new();
+        let mut enqueued_slashes_total = 0;
+        for (validator, slashes) in RPC.vp().pos().enqueued_slashes(&self.client).await? {
+            for slash in slashes.values().flatten() {
+                enqueued_slashes_total += 1;
+                if slash.epoch.0.abs_diff(epoch.0) > window
+                    || !seen.insert((validator.clone(), slash.epoch))
+                {
+                    continue;
+                }
+                let slash_total_stake = match total_stakes.get(&slash.epoch) {
+                    Some(total_stake) => *total_stake,
+                    None => {
+                        let total_stake =
+                            rpc::get_total_staked_tokens(&self.client, slash.epoch).await?;
+                        total_stakes.insert(slash.epoch, total_stake);
+                        total_stake
+                    }
+                };
+                enqueued_slashes.push(EnqueuedSlash {
+                    validator: validator.to_string(),
+                    infraction_epoch: slash.epoch.0,
+                    stake: rpc::get_validator_stake(&self.client, slash.epoch, &validator).await?,
+                    total_stake: slash_total_stake,
+                });
+            }
+        }
+        // Redelegated bonds stay slashable for infractions of the source validator, so bonds
+        // redelegated in either direction add to the exposure of the validator.
+        let incoming_redelegated = self
+            .query_amount_sum_async(
+                &proof_of_stake::storage_key::validator_total_redelegated_bonded_key(&addr),
+            )
+            .await?;
+        let outgoing_redelegated = self
+            .query_amount_sum_async(
+                &proof_of_stake::storage_key::validator_outgoing_redelegations_key(&addr),
+            )
+            .await?;
+        Ok(SlashExposure {
+            epoch: epoch.0,
+            stake,
+            total_stake,
+            enqueued_slashes,
+            enqueued_slashes_total,
+            incoming_redelegated,
+            outgoing_redelegated,
+        })
+    }
+    /// Sums the amounts stored under a storage prefix, e.g. the leaves of the nested PoS
+    /// redelegation maps.
+    async fn query_amount_sum_async(&self, prefix: &Key) -> Result<Amount, Box<dyn Error>> {
+        let values = RPC
+            .shell()
+            .storage_prefix(&self.client, None, None, false, prefix)
+            .await?;
+        let mut total = Amount::zero();
+        for value in values.data {
+            let amount = Amount::try_from_slice(&value.value)?;
+            total = total
+                .checked_add(amount)
+                .ok_or(format!("Amount overflow summing {}", prefix))?;
+        }
+        Ok(total)
+    }
+    pub fn query_slash_exposure(
+        &self,
+        address: &str,
+        pos_params: &PosParams,
+    ) -> Result<SlashExposure, Box<dyn Error>> {
+        let result = block_in_place(|| {
+            let runtime_handle = Handle::current();
+            runtime_handle.block_on(self.query_slash_exposure_async(address, pos_params))
+        })?;
+        Ok(result)
+    }
+}
+
+/// PGF payment proposals make their payments once their activation epoch is reached.
+fn is_activated_pgf_payment(proposal: &StorageProposal, epoch: u64) -> bool {
+    matches!(proposal.r#type, ProposalType::PGFPayment(_)) && proposal.activation_epoch.0 <= epoch
+}
+
+/// Retroactive payments of a PGF payment proposal, which are only made if the proposal passed.
+fn proposal_retro_payments(
+    proposal_id: u64,
+    proposal: &StorageProposal,
+    passed: bool,
+) -> Vec<PgfRetroPayment> {
+    let ProposalType::PGFPayment(actions) = &proposal.r#type else {
+        return vec![];
+    };
+    if !passed {
+        return vec![];
+    }
+    actions
+        .iter()
+        .filter_map(|action| match action {
+            PGFAction::Retro(target) => Some(PgfRetroPayment {
+                target: target.target(),
+                proposal_id,
+                epoch: proposal.activation_epoch.0,
+                amount: target.amount(),
+            }),
+            PGFAction::Continuous(_) => None,
+        })
+        .collect()
+}
+
+/// Decodes a Tendermint client state, stored `Any` encoded by Namada.
+fn decode_client_state(bytes: &[u8]) -> Result<TmClientState, Box<dyn Error>> {
+    Ok(<TmClientState as Protobuf<Any>>::decode_vec(bytes)?)
+}
+
+/// Decodes a Tendermint consensus state, stored `Any` encoded by Namada.
+fn decode_consensus_state(bytes: &[u8]) -> Result<TmConsensusState, Box<dyn Error>> {
+    Ok(<TmConsensusState as Protobuf<Any>>::decode_vec(bytes)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use namada_sdk::governance::storage::proposal::{AddRemove, PGFInternalTarget, PGFTarget};
+    use namada_sdk::ibc::clients::tendermint::types::{AllowUpdate, TrustThreshold};
+    use namada_sdk::ibc::core::client::types::Height as IbcHeight;
+    use namada_sdk::ibc::core::commitment_types::commitment::CommitmentRoot;
+    use namada_sdk::ibc::core::commitment_types::specs::ProofSpecs;
+    use namada_sdk::ibc::core::host::types::identifiers::ChainId;
+    use std::collections::{BTreeMap, BTreeSet};
+
+    fn address(byte: u8) -> Address {
+        Address::Established([byte; 20].into())
+    }
+
+    fn pgf_target(byte: u8, amount: u64) -> PGFTarget {
+        PGFTarget::Internal(PGFInternalTarget {
+            target: address(byte),
+            amount: Amount::from_uint(amount, 0).unwrap(),
+        })
+    }
+
+    fn proposal(r#type: ProposalType, activation_epoch: u64) -> StorageProposal {
+        StorageProposal {
+            id: 42,
+            content: BTreeMap::new(),
+            author: address(9),
+            r#type,
+            voting_start_epoch: (activation_epoch - 3).into(),
+            voting_end_epoch: (activation_epoch - 1).into(),
+            activation_epoch: activation_epoch.into(),
+        }
+    }
+
+    fn pgf_payment(activation_epoch: u64) -> StorageProposal {
+        let actions = BTreeSet::from([
+            PGFAction::Retro(pgf_target(1, 1_000_000)),
+            PGFAction::Retro(pgf_target(2, 2_500_000)),
+            PGFAction::Continuous(AddRemove::Add(pgf_target(3, 100_000))),
+        ]);
+        proposal(ProposalType::PGFPayment(actions), activation_epoch)
+    }
+
+    #[test]
+    fn pgf_payments_are_made_from_the_activation_epoch() {
+        assert!(!is_activated_pgf_payment(&pgf_payment(100), 99));
+        assert!(is_activated_pgf_payment(&pgf_payment(100), 100));
+        assert!(is_activated_pgf_payment(&pgf_payment(100), 150));
+        assert!(!is_activated_pgf_payment(
+            &proposal(ProposalType::Default, 100),
+            150
+        ));
+    }
+
+    #[test]
+    fn passed_proposals_make_their_retro_payments() {
+        let payments = proposal_retro_payments(42, &pgf_payment(100), true);
+        let payments: Vec<_> = payments
+            .iter()
+            .map(|payment| {
+                (
+                    payment.target.as_str(),
+                    payment.proposal_id,
+                    payment.epoch,
+                    payment.amount,
+                )
+            })
+            .collect();
+        let (first, second) = (address(1).to_string(), address(2).to_string());
+        assert_eq!(payments.len(), 2);
+        assert!(payments.contains(&(
+            first.as_str(),
+            42,
+            100,
+            Amount::from_uint(1_000_000u64, 0).unwrap()
+        )));
+        assert!(payments.contains(&(
+            second.as_str(),
+            42,
+            100,
+            Amount::from_uint(2_500_000u64, 0).unwrap()
+        )));
+    }
+
+    #[test]
+    fn rejected_and_other_proposals_make_no_retro_payments() {
+        assert!(proposal_retro_payments(42, &pgf_payment(100), false).is_empty());
+        assert!(
+            proposal_retro_payments(42, &proposal(ProposalType::Default, 100), true).is_empty()
+        );
+    }
+
+    fn client_state() -> TmClientState {
+        TmClientState::new(
+            ChainId::new("cosmoshub-4").unwrap(),
+            TrustThreshold::ONE_THIRD,
+            Duration::from_secs(14 * 24 * 3600),
+            Duration::from_secs(21 * 24 * 3600),
+            Duration::from_secs(40),
+            IbcHeight::new(4, 22_000_000).unwrap(),
+            ProofSpecs::cosmos(),
+            vec!["upgrade".to_string(), "upgradedIBCState".to_string()],
+            AllowUpdate {
+                after_expiry: true,
+                after_misbehaviour: true,
+            },
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn decodes_any_encoded_client_state() {
+        let bytes = <TmClientState as Protobuf<Any>>::encode_vec(client_state());
+        let decoded = decode_client_state(&bytes).unwrap();
+        assert_eq!(decoded.chain_id.as_str(), "cosmoshub-4");
+        assert_eq!(decoded.latest_height.revision_height(), 22_000_000);
+        assert_eq!(decoded.trusting_period, Duration::from_secs(14 * 24 * 3600));
+        assert!(decoded.frozen_height.is_none());
+    }
+
+    #[test]
+    fn rejects_raw_encoded_client_state() {
+        let raw_bytes = <TmClientState as Protobuf<
+            namada_sdk::ibc::clients::tendermint::types::proto::v1::ClientState,
+        >>::encode_vec(client_state());
+        assert!(decode_client_state(&raw_bytes).is_err());
+    }
+
+    #[test]
+    fn decodes_any_encoded_consensus_state() {
+        let consensus_state = TmConsensusState::new(
+            CommitmentRoot::from_bytes(&[1; 32]),
+            tendermint::Time::from_unix_timestamp(1_700_000_000, 0).unwrap(),
+            tendermint::Hash::Sha256([2; 32]),
+        );
+        let bytes = <TmConsensusState as Protobuf<Any>>::encode_vec(consensus_state);
+
+        let decoded = decode_consensus_state(&bytes).unwrap();
+        assert_eq!(decoded.timestamp.unix_timestamp(), 1_700_000_000);
+    }
 }
@@ -1,8 +1,19 @@
+use crate::auth::{auth_middleware, Authenticator};
+use crate::block_follower::TxStats;
 use crate::cli::ExporterConfig;
-use crate::handlers::metrics_handler;
+use crate::collector::SharedSnapshot;
+use crate::handlers::{
+    healthz_handler, metrics_handler, readyz_handler, status_handler, status_schema_handler,
+    validator_events_handler,
+};
+use crate::healthcheck::HealthCheckStats;
 use crate::namada_query::Query;
-use axum::{response::IntoResponse, routing::get, Router};
+use crate::tls::TlsReloader;
+use axum::{middleware, response::IntoResponse, routing::get, Router};
+use axum_server::tls_rustls::RustlsConfig;
 use log::info;
+use std::net::ToSocketAddrs;
+use std::sync::{Arc, RwLock};
 async fn health_handler() -> impl IntoResponse {
     String::from("OK").into_response()
 }
@@ -10,18 +21,67 @@ async fn health_handler() -> impl IntoResponse {
 pub struct ServerState {
     pub q: Query,
     pub config: ExporterConfig,
+    pub tx_stats: Arc<RwLock<TxStats>>,
+    pub snapshot: SharedSnapshot,
+    pub healthcheck_stats: Arc<RwLock<HealthCheckStats>>,
 }
-pub async fn start_server(exporter_config: &ExporterConfig, q: Query) {
-    let app = Router::new()
+pub async fn start_server(
+    exporter_config: &ExporterConfig,
+    q: Query,
+    tx_stats: Arc<RwLock<TxStats>>,
+    snapshot: SharedSnapshot,
+    healthcheck_stats: Arc<RwLock<HealthCheckStats>>,
+) {
+    let mut app = Router::new()
         .route("/", get(health_handler))
+        .route("/healthz", get(healthz_handler))
+        .route("/readyz", get(readyz_handler))
         .route("/metrics", get(metrics_handler))
+        .route("/api/v1/status", get(status_handler))
+        .route("/api/v1/status/schema", get(status_schema_handler))
+        .route("/api/v1/validator/events", get(validator_events_handler))
         .with_state(ServerState {
             q,
             config: exporter_config.clone(),
+            tx_stats,
+            snapshot,
+            healthcheck_stats,
         });
-    let listener = tokio::net::TcpListener::bind(exporter_config.host.clone())
-        .await
-        .unwrap();
-    info!("Server listening on {}", exporter_config.host);
-    axum::serve(listener, app).await.unwrap();
+    if let Some(auth_config) = &exporter_config.auth {
+        info!("Auth enabled with config: {:?}", auth_config);
+        app = app.layer(middleware::from_fn_with_state(
+            Authenticator::new(auth_config),
+            auth_middleware,
+        ));
+    }
+    match &exporter_config.tls {
+        Some(tls_config) => {
+            let rustls_config =
+                RustlsConfig::from_pem_file(&tls_config.cert_path, &tls_config.key_path)
+                    .await
+                    .expect("Failed to load TLS certificate and key");
+            let tls_reloader = TlsReloader::new(tls_config.clone(), rustls_config.clone());
+            tokio::spawn(async move {
+                tls_reloader.start_reload_loop().await;
+            });
+            let addr = exporter_config
+                .host
+                .to_socket_addrs()
+                .expect("Failed to resolve host")
+                .next()
+                .expect("Host resolved to no address");
+            info!("Server listening on {} with TLS", exporter_config.host);
+            axum_server::bind_rustls(addr, rustls_config)
+                .serve(app.into_make_service())
+                .await
+                .unwrap();
+        }
+        None => {
+            let listener = tokio::net::TcpListener::bind(exporter_config.host.clone())
+                .await
+                .unwrap();
+            info!("Server listening on {}", exporter_config.host);
+            axum::serve(listener, app).await.unwrap();
+        }
+    }
 }
@@ -0,0 +1,213 @@
+use crate::collector::Snapshot;
+use serde::Serialize;
+use std::time::UNIX_EPOCH;
+
+/// Version of the `/api/v1/status` response, bumped on breaking changes of `status.schema.json`.
+pub const STATUS_API_VERSION: u32 = 1;
+
+pub const STATUS_SCHEMA: &str = include_str!("../status.schema.json");
+
+#[derive(Debug, Serialize)]
+pub struct Status {
+    pub version: u32,
+    /// Unix timestamp of the collection the status is based on
+    pub collected_at: u64,
+    pub validator: ValidatorStatus,
+    pub network: NetworkStatus,
+    pub node: NodeStatus,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ValidatorStatus {
+    pub address: String,
+    pub address_hash: Option<String>,
+    pub state: &'static str,
+    pub uptime_percentage: Option<u64>,
+    pub active_set_rank: Option<u64>,
+    pub missed_blocks: Option<u64>,
+    /// Total bonds in NAM
+    pub total_bonds: Option<f64>,
+    pub commission_rate: Option<f64>,
+    pub consensus_key_mismatch: Option<bool>,
+    pub pending_consensus_key_epoch: Option<u64>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct NetworkStatus {
+    pub chain_id: String,
+    pub epoch: Option<u64>,
+    pub max_set_size: Option<u64>,
+    pub active_set_size: Option<u64>,
+    /// Stake in NAM of the last validator in the consensus set
+    pub lowest_active_set_stake: Option<f64>,
+    /// Minimum stake in NAM to be in the active set
+    pub stake_threshold: Option<f64>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct NodeStatus {
+    pub node_id: String,
+    pub moniker: String,
+    pub latest_block_height: Option<u64>,
+    /// RFC 3339 time of the latest block
+    pub latest_block_time: String,
+    pub catching_up: bool,
+    pub voting_power: Option<u64>,
+    pub is_signing_validator: bool,
+}
+
+/// Names of the `namada_validator_state` values.
+pub fn validator_state_name(state: i64) -> &'static str {
+    match state {
+        1 => "consensus",
+        2 => "below_capacity",
+        3 => "below_threshold",
+        4 => "jailed",
+        5 => "inactive",
+        _ => "unknown",
+    }
+}
+
+impl Status {
+    pub fn from_snapshot(snapshot: &Snapshot, validator_tm_address: &str) -> Self {
+        let validator = &snapshot.validator;
+        let network = &snapshot.network;
+        let node = &snapshot.node;
+        Status {
+            version: STATUS_API_VERSION,
+            collected_at: snapshot
+                .collected_at
+                .duration_since(UNIX_EPOCH)
+                .map(|d| d.as_secs())
+                .unwrap_or_default(),
+            validator: ValidatorStatus {
+                address: validator_tm_address.to_string(),
+                address_hash: validator.validator_address_hash.clone(),
+                state: validator
+                    .namada_validator_state
+                    .map(validator_state_name)
+                    .unwrap_or("unknown"),
+                // Validators which missed more blocks than the liveness threshold are at 0% uptime
+                uptime_percentage: validator
+                    .namada_validator_uptime_percentage
+                    .map(|uptime| uptime.max(0) as u64),
+                active_set_rank: validator
+                    .namada_validator_active_set_rank
+                    .map(|rank| rank as u64),
+                missed_blocks: validator.namada_missed_blocks.map(|missed| missed as u64),
+                total_bonds: validator.namada_total_bonds,
+                commission_rate: validator.validator_commission,
+                consensus_key_mismatch: validator.namada_validator_consensus_key_mismatch,
+                pending_consensus_key_epoch: validator
+                    .namada_validator_pending_consensus_key_epoch
+                    .map(|epoch| epoch as u64),
+            },
+            network: NetworkStatus {
+                chain_id: snapshot.status.node_info.network.to_string(),
+                epoch: Some(network.namada_network_epoch as u64),
+                max_set_size: Some(network.namada_network_max_set_size as u64),
+                active_set_size: Some(network.namada_network_active_set_size as u64),
+                lowest_active_set_stake: network.namada_network_lowest_active_set_stake,
+                stake_threshold: Some(network.namada_network_stake_threshold),
+            },
+            node: NodeStatus {
+                node_id: node.node_id.clone(),
+                moniker: node.moniker.clone(),
+                latest_block_height: Some(node.namada_node_latest_block as u64),
+                latest_block_time: snapshot.status.sync_info.latest_block_time.to_rfc3339(),
+                catching_up: snapshot.status.sync_info.catching_up,
+                voting_power: Some(node.namada_node_validator_voting_power as u64),
+                is_signing_validator: node.namada_node_is_signing_validator == 1,
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::collector::tests::{snapshot, CHAIN_ID, VALIDATOR_HASH};
+    use crate::metrics::ValidatorMetricsData;
+    use serde_json::Value;
+
+    const VALIDATOR: &str = "tnam1q8unq87chw62cmn6j2v7xfwr52xre06rxq6x4yuq";
+
+    #[test]
+    fn maps_known_values() {
+        let status = Status::from_snapshot(&snapshot(), VALIDATOR);
+        assert_eq!(status.version, STATUS_API_VERSION);
+        assert_eq!(status.validator.address, VALIDATOR);
+        assert_eq!(
+            status.validator.address_hash.as_deref(),
+            Some(VALIDATOR_HASH)
+        );
+        assert_eq!(status.validator.state, "consensus");
+        assert_eq!(status.validator.uptime_percentage, Some(99));
+        assert_eq!(status.validator.active_set_rank, Some(12));
+        assert_eq!(status.validator.missed_blocks, Some(3));
+        assert_eq!(status.validator.total_bonds, Some(1520000.5));
+        assert_eq!(status.validator.consensus_key_mismatch, Some(false));
+        assert_eq!(status.validator.pending_consensus_key_epoch, None);
+        assert_eq!(status.network.chain_id, CHAIN_ID);
+        assert_eq!(status.network.epoch, Some(512));
+        assert_eq!(status.node.latest_block_height, Some(270000));
+        assert!(status.node.is_signing_validator);
+        assert!(!status.node.catching_up);
+    }
+
+    #[test]
+    fn maps_unknown_values_to_none() {
+        let mut snapshot = snapshot();
+        snapshot.validator = ValidatorMetricsData::default();
+        snapshot.network.namada_network_lowest_active_set_stake = None;
+        let status = Status::from_snapshot(&snapshot, VALIDATOR);
+        assert_eq!(status.validator.address_hash, None);
+        assert_eq!(status.validator.state, "unknown");
+        assert_eq!(status.validator.uptime_percentage, None);
+        assert_eq!(status.validator.active_set_rank, None);
+        assert_eq!(status.validator.missed_blocks, None);
+        assert_eq!(status.validator.total_bonds, None);
+        assert_eq!(status.validator.commission_rate, None);
+        assert_eq!(status.validator.consensus_key_mismatch, None);
+        assert_eq!(status.network.lowest_active_set_stake, None);
+    }
+
+    #[test]
+    fn jailed_validator_has_state_without_rank() {
+        let mut snapshot = snapshot();
+        snapshot.validator.namada_validator_state = Some(4);
+        snapshot.validator.namada_validator_active_set_rank = None;
+        let status = Status::from_snapshot(&snapshot, VALIDATOR);
+        assert_eq!(status.validator.state, "jailed");
+        assert_eq!(status.validator.active_set_rank, None);
+        assert_eq!(status.validator.missed_blocks, Some(3));
+    }
+
+    #[test]
+    fn serializes_all_required_schema_fields() {
+        let schema: Value = serde_json::from_str(STATUS_SCHEMA).unwrap();
+        let status = serde_json::to_value(Status::from_snapshot(&snapshot(), VALIDATOR)).unwrap();
+        assert_eq!(schema["properties"]["version"]["const"], STATUS_API_VERSION);
+        let required = |schema: &Value| -> Vec<String> {
+            schema["required"]
+                .as_array()
+                .unwrap()
+                .iter()
+                .map(|field| field.as_str().unwrap().to_string())
+                .collect()
+        };
+        for field in required(&schema) {
+            assert!(status.get(&field).is_some(), "missing {}", field);
+        }
+        for section in ["validator", "network", "node"] {
+            for field in required(&schema["properties"][section]) {
+                assert!(
+                    status[section].get(&field).is_some(),
+                    "missing {}.{}",
+                    section,
+                    field
+                );
+            }
+        }
+    }
+}
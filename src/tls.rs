@@ -0,0 +1,60 @@
+use crate::cli::TlsConfig;
+use axum_server::tls_rustls::RustlsConfig;
+use log::{error, info};
+use std::fs;
+use std::time::SystemTime;
+use tokio::time::interval;
+
+pub struct TlsReloader {
+    config: TlsConfig,
+    rustls_config: RustlsConfig,
+}
+
+impl TlsReloader {
+    pub fn new(config: TlsConfig, rustls_config: RustlsConfig) -> Self {
+        Self {
+            config,
+            rustls_config,
+        }
+    }
+
+    /// Reloads the certificate and key whenever one of the files changes.
+    pub async fn start_reload_loop(&self) {
+        let mut interval_timer = interval(self.config.reload_interval());
+        let mut last_modified = self.modified();
+
+        info!(
+            "Starting TLS reload loop, checking {} and {} every {:?}",
+            self.config.cert_path.display(),
+            self.config.key_path.display(),
+            self.config.reload_interval()
+        );
+
+        loop {
+            interval_timer.tick().await;
+            let modified = self.modified();
+            if modified == last_modified {
+                continue;
+            }
+            match self
+                .rustls_config
+                .reload_from_pem_file(&self.config.cert_path, &self.config.key_path)
+                .await
+            {
+                Ok(()) => {
+                    info!("Reloaded TLS certificate and key");
+                    last_modified = modified;
+                }
+                // Keep serving the previous certificate and retry on the next tick,
+                // e.g. when the certificate was written but the key not yet
+                Err(e) => error!("Could not reload TLS certificate and key: {}", e),
+            }
+        }
+    }
+
+    fn modified(&self) -> Option<(SystemTime, SystemTime)> {
+        let cert_modified = fs::metadata(&self.config.cert_path).and_then(|m| m.modified());
+        let key_modified = fs::metadata(&self.config.key_path).and_then(|m| m.modified());
+        cert_modified.ok().zip(key_modified.ok())
+    }
+}
@@ -0,0 +1,269 @@
+use crate::cli::{ConfigArgs, ExporterConfig};
+use crate::namada_query::Query;
+use humantime::parse_duration;
+use namada_sdk::address::Address;
+use std::str::FromStr;
+
+/// Validates the configuration and prints all problems found.
+/// Returns the process exit code, non-zero if there are problems.
+pub fn validate(args: &ConfigArgs) -> i32 {
+    let problems = match args.load() {
+        Ok(config) => check_config(&config),
+        Err(e) => e.into_iter().map(|e| e.to_string()).collect(),
+    };
+    if problems.is_empty() {
+        println!("Config is valid");
+        return 0;
+    }
+    eprintln!("Found {} problem(s) in the config:", problems.len());
+    for problem in &problems {
+        eprintln!("  - {}", problem);
+    }
+    1
+}
+
+fn check_config(config: &ExporterConfig) -> Vec<String> {
+    let mut problems = Vec::new();
+    check_address(
+        &mut problems,
+        "validator_tm_address",
+        &config.validator_tm_address,
+    );
+    for (i, balance) in config.balances.iter().flatten().enumerate() {
+        check_address(
+            &mut problems,
+            &format!("balances[{}].address", i),
+            &balance.address,
+        );
+        if let Some(token) = &balance.token {
+            check_address(&mut problems, &format!("balances[{}].token", i), token);
+        }
+    }
+    for (i, token) in config.tokens.iter().flatten().enumerate() {
+        check_address(
+            &mut problems,
+            &format!("tokens[{}].address", i),
+            &token.address,
+        );
+    }
+    for (i, steward) in config.pgf.iter().flat_map(|pgf| &pgf.stewards).enumerate() {
+        check_address(&mut problems, &format!("pgf.stewards[{}]", i), steward);
+    }
+    for (field, value) in durations(config) {
+        if let Err(e) = parse_duration(value) {
+            problems.push(format!(
+                "{} \"{}\" is not a valid duration, e.g. \"10s\" or \"1m\": {}",
+                field, value, e
+            ));
+        }
+    }
+    if let Some(tls) = &config.tls {
+        for (field, path) in [
+            ("tls.cert_path", &tls.cert_path),
+            ("tls.key_path", &tls.key_path),
+        ] {
+            if !path.is_file() {
+                problems.push(format!("{} {} does not exist", field, path.display()));
+            }
+        }
+    }
+    check_rpc(&mut problems, config);
+    problems
+}
+
+fn check_address(problems: &mut Vec<String>, field: &str, address: &str) {
+    if let Err(e) = Address::from_str(address) {
+        problems.push(format!(
+            "{} \"{}\" is not a valid Namada address: {}",
+            field, address, e
+        ));
+    }
+}
+
+fn check_rpc(problems: &mut Vec<String>, config: &ExporterConfig) {
+    let q = match Query::create(&config.http_rpc) {
+        Ok(q) => q,
+        Err(e) => {
+            problems.push(format!(
+                "http_rpc \"{}\" is not a valid URL: {}",
+                config.http_rpc, e
+            ));
+            return;
+        }
+    };
+    let status = match q.status() {
+        Ok(status) => status,
+        Err(e) => {
+            problems.push(format!(
+                "http_rpc {} is not reachable: {}",
+                config.http_rpc, e
+            ));
+            return;
+        }
+    };
+    let chain_id = status.node_info.network.to_string();
+    if let Some(expected_chain_id) = &config.chain_id {
+        if *expected_chain_id != chain_id {
+            problems.push(format!(
+                "chain_id \"{}\" does not match the chain id \"{}\" of http_rpc {}",
+                expected_chain_id, chain_id, config.http_rpc
+            ));
+        }
+    }
+}
+
+/// All configured duration fields with their path in the config.
+fn durations(config: &ExporterConfig) -> Vec<(&'static str, &str)> {
+    let mut durations = Vec::new();
+    if let Some(collect_rate) = &config.collect_rate {
+        durations.push(("collect_rate", collect_rate.as_str()));
+    }
+    if let Some(freshness_window) = &config.freshness_window {
+        durations.push(("freshness_window", freshness_window.as_str()));
+    }
+    if let Some(tls) = &config.tls {
+        if let Some(reload_rate) = &tls.reload_rate {
+            durations.push(("tls.reload_rate", reload_rate.as_str()));
+        }
+    }
+    if let Some(healthcheck) = &config.healthcheck {
+        durations.push(("healthcheck.ping_rate", healthcheck.ping_rate.as_str()));
+        durations.push(("healthcheck.timeout", healthcheck.timeout.as_str()));
+        if let Some(retry_backoff) = &healthcheck.retry_backoff {
+            durations.push(("healthcheck.retry_backoff", retry_backoff.as_str()));
+        }
+        if let Some(max_block_age) = healthcheck
+            .conditions
+            .as_ref()
+            .and_then(|conditions| conditions.max_block_age.as_ref())
+        {
+            durations.push((
+                "healthcheck.conditions.max_block_age",
+                max_block_age.as_str(),
+            ));
+        }
+    }
+    if let Some(block_follower) = &config.block_follower {
+        durations.push((
+            "block_follower.poll_rate",
+            block_follower.poll_rate.as_str(),
+        ));
+    }
+    if let Some(alerts) = &config.alerts {
+        if let Some(eval_rate) = &alerts.eval_rate {
+            durations.push(("alerts.eval_rate", eval_rate.as_str()));
+        }
+        if let Some(timeout) = &alerts.timeout {
+            durations.push(("alerts.timeout", timeout.as_str()));
+        }
+        if let Some(max_node_lag) = &alerts.rules.max_node_lag {
+            durations.push(("alerts.rules.max_node_lag", max_node_lag.as_str()));
+        }
+    }
+    durations
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use figment::providers::{Format, Toml};
+    use figment::Figment;
+    use std::net::TcpListener;
+
+    fn config(toml: &str) -> ExporterConfig {
+        Figment::from(Toml::string(toml)).extract().unwrap()
+    }
+
+    fn has_problem(problems: &[String], prefix: &str) -> bool {
+        problems.iter().any(|problem| problem.starts_with(prefix))
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn collects_all_problems() {
+        // A port that was just free, so nothing answers the RPC
+        let http_rpc = {
+            let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+            format!("http://{}", listener.local_addr().unwrap())
+        };
+        let config = config(&format!(
+            r#"
+host = "0.0.0.0:9000"
+validator_tm_address = "tnam1invalid"
+http_rpc = "{}"
+collect_rate = "10 parsecs"
+
+[tls]
+cert_path = "/nonexistent/cert.pem"
+key_path = "/nonexistent/key.pem"
+
+[healthcheck]
+ping_rate = "30s"
+timeout = "soon"
+
+[[tokens]]
+address = "nam"
+"#,
+            http_rpc
+        ));
+        let problems = check_config(&config);
+        assert_eq!(problems.len(), 7, "{:#?}", problems);
+        assert!(has_problem(
+            &problems,
+            "validator_tm_address \"tnam1invalid\""
+        ));
+        assert!(has_problem(&problems, "tokens[0].address \"nam\""));
+        assert!(has_problem(&problems, "collect_rate \"10 parsecs\""));
+        assert!(has_problem(&problems, "healthcheck.timeout \"soon\""));
+        assert!(has_problem(
+            &problems,
+            "tls.cert_path /nonexistent/cert.pem"
+        ));
+        assert!(has_problem(&problems, "tls.key_path /nonexistent/key.pem"));
+        assert!(has_problem(
+            &problems,
+            &format!("http_rpc {} is not reachable", http_rpc)
+        ));
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn reports_invalid_rpc_url() {
+        let config = config(
+            r#"
+host = "0.0.0.0:9000"
+validator_tm_address = "tnam1invalid"
+http_rpc = "not a url"
+"#,
+        );
+        let problems = check_config(&config);
+        assert!(has_problem(
+            &problems,
+            "http_rpc \"not a url\" is not a valid URL"
+        ));
+    }
+
+    #[test]
+    fn durations_lists_configured_fields() {
+        let config = config(
+            r#"
+host = "0.0.0.0:9000"
+validator_tm_address = "tnam1invalid"
+http_rpc = "http://127.0.0.1:26657"
+collect_rate = "10s"
+
+[healthcheck]
+ping_rate = "30s"
+timeout = "5s"
+retry_backoff = "1s"
+"#,
+        );
+        assert_eq!(
+            durations(&config),
+            vec![
+                ("collect_rate", "10s"),
+                ("healthcheck.ping_rate", "30s"),
+                ("healthcheck.timeout", "5s"),
+                ("healthcheck.retry_backoff", "1s"),
+            ]
+        );
+    }
+}